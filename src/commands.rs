@@ -11,4 +11,14 @@ pub use result::CommandResult;
 mod indicate;
 pub use indicate::IndicateCommand;
 mod solve;
-pub use solve::SolveCommand;
\ No newline at end of file
+pub use solve::SolveCommand;
+mod unique;
+pub use unique::UniqueCommand;
+mod check;
+pub use check::CheckCommand;
+mod rate;
+pub use rate::RateCommand;
+mod hint;
+pub use hint::HintCommand;
+mod gen;
+pub use gen::GenCommand;
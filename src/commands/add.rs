@@ -1,4 +1,4 @@
-use crate::game::{AddResult, SudokuBoard};
+use sudoku_solver::game::{AddResult, SudokuBoard};
 
 use super::{Command, CommandResult};
 
@@ -24,34 +24,21 @@ impl Command for AddCommand {
     fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
         let row = match args[0].parse::<usize>() {
             Ok(n) => n,
-            Err(_) => {
-                return CommandResult::ParseError;
-            }
+            Err(_) => return CommandResult::ParseError,
         };
         let col = match args[1].parse::<usize>() {
             Ok(n) => n,
-            Err(_) => {
-                return CommandResult::ParseError;
-            }
+            Err(_) => return CommandResult::ParseError,
         };
-        let val = args[2].clone();
+        let val = args[2];
 
         match board.add_str(row, col, val) {
-            AddResult::Added(v) => {
-                return CommandResult::AddCommandSuccess(v, row, col);
-            }
-            AddResult::NoneValue => {
-                return CommandResult::AddCommandNoneValue;
-            }
-            AddResult::NotPossible => {
-                return CommandResult::AddCommandNotPossible;
-            }
-            AddResult::AlreadySet => {
-                return CommandResult::AddCommandAlreadySet;
-            }
-            AddResult::Solved => {
-                return CommandResult::AddCommandSolved;
-            }
+            AddResult::Added(v) => CommandResult::AddCommandSuccess(v, row, col),
+            AddResult::NoneValue => CommandResult::AddCommandNoneValue,
+            AddResult::NotPossible => CommandResult::AddCommandNotPossible,
+            AddResult::AlreadySet => CommandResult::AddCommandAlreadySet,
+            AddResult::Incorrect => CommandResult::AddCommandIncorrect,
+            AddResult::Solved => CommandResult::AddCommandSolved,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::game::{ChangeResult, SudokuBoard};
+use sudoku_solver::game::{ChangeResult, SudokuBoard};
 
 use super::{Command, CommandResult};
 
@@ -24,34 +24,23 @@ impl Command for ChangeCommand {
     fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
         let row = match args[0].parse::<usize>() {
             Ok(n) => n,
-            Err(_) => {
-                return CommandResult::ParseError;
-            }
+            Err(_) => return CommandResult::ParseError,
         };
         let col = match args[1].parse::<usize>() {
             Ok(n) => n,
-            Err(_) => {
-                return CommandResult::ParseError;
-            }
+            Err(_) => return CommandResult::ParseError,
         };
-        let val = args[2].clone();
+        let val = args[2];
 
         match board.change_str(row, col, val) {
             ChangeResult::Changed(rem_v, add_v) => {
-                return CommandResult::ChangeCommandSuccess(rem_v, add_v, row, col);
-            }
-            ChangeResult::NoneValue => {
-                return CommandResult::ChangeCommandNoneValue;
-            }
-            ChangeResult::NotPossible => {
-                return CommandResult::ChangeCommandNotPossible;
-            }
-            ChangeResult::Immutable => {
-                return CommandResult::ChangeCommandImmutable;
-            }
-            ChangeResult::Solved => {
-                return CommandResult::ChangeCommandSolved;
+                CommandResult::ChangeCommandSuccess(rem_v, add_v, row, col)
             }
+            ChangeResult::NoneValue => CommandResult::ChangeCommandNoneValue,
+            ChangeResult::NotPossible => CommandResult::ChangeCommandNotPossible,
+            ChangeResult::Immutable => CommandResult::ChangeCommandImmutable,
+            ChangeResult::Incorrect => CommandResult::ChangeCommandIncorrect,
+            ChangeResult::Solved => CommandResult::ChangeCommandSolved,
         }
     }
 }
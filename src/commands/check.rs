@@ -0,0 +1,37 @@
+use sudoku_solver::SudokuBoard;
+
+use super::{Command, CommandResult};
+
+pub struct CheckCommand;
+
+impl Command for CheckCommand {
+    fn name(&self) -> &'static str {
+        "check"
+    }
+
+    fn num_args(&self) -> usize {
+        0
+    }
+
+    fn usage(&self) -> &'static str {
+        "Usage: `check <optional -g to toggle guard mode>`"
+    }
+
+    fn description(&self) -> &'static str {
+        "Marks the values that disagree with the solution, in guard mode such values are refused"
+    }
+
+    fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
+        match args.first() {
+            Some(&"-g") => {
+                let guard = !board.is_guarded();
+                match board.set_guard(guard) {
+                    Ok(()) => CommandResult::CheckCommandGuard(guard),
+                    Err(result) => CommandResult::CheckCommandSuccess(result),
+                }
+            }
+            Some(_) => CommandResult::ParseError,
+            None => CommandResult::CheckCommandSuccess(board.check()),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::SudokuBoard;
+use sudoku_solver::SudokuBoard;
 
 use super::CommandResult;
 
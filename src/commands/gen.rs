@@ -0,0 +1,37 @@
+use sudoku_solver::{generator::generate, logic::Grade, SudokuBoard};
+
+use super::{Command, CommandResult};
+
+pub struct GenCommand;
+
+impl Command for GenCommand {
+    fn name(&self) -> &'static str {
+        "gen"
+    }
+
+    fn num_args(&self) -> usize {
+        1
+    }
+
+    fn usage(&self) -> &'static str {
+        "Usage: `gen <grade: easy, medium, hard, expert, extreme>`"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates a random puzzle of the given difficulty and replaces the board with it"
+    }
+
+    fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
+        let Some(grade) = Grade::from_name(args[0]) else {
+            return CommandResult::ParseError;
+        };
+
+        match generate(grade) {
+            Some(puzzle) => {
+                *board = puzzle;
+                CommandResult::GenCommandSuccess(board.rate())
+            }
+            None => CommandResult::GenCommandFailed(grade),
+        }
+    }
+}
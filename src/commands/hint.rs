@@ -0,0 +1,114 @@
+use sudoku_solver::{
+    logic::{LogicalSolver, Step},
+    SudokuBoard,
+};
+
+use super::{Command, CommandResult};
+
+pub struct HintCommand;
+
+impl HintCommand {
+    /// Returns the lines of a hint, the technique first, then the region and the full deduction
+    /// as the detail allows.
+    pub fn lines(step: &Step, detail: usize) -> Vec<String> {
+        let mut lines = vec![format!("Try the {} technique", step.technique)];
+        if detail >= 2 {
+            lines.push(step.region());
+        }
+        if detail >= 3 {
+            lines.push(step.explanation());
+        }
+        lines
+    }
+}
+
+impl Command for HintCommand {
+    fn name(&self) -> &'static str {
+        "hint"
+    }
+
+    fn num_args(&self) -> usize {
+        0
+    }
+
+    fn usage(&self) -> &'static str {
+        "Usage: `hint <optional detail: 1 technique, 2 region, 3 full deduction (default)>`"
+    }
+
+    fn description(&self) -> &'static str {
+        "Explains the next logical step, use a lower detail to only get a nudge"
+    }
+
+    fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
+        let detail = match args.first() {
+            Some(detail) => match detail.parse::<usize>() {
+                Ok(detail @ 1..=3) => detail,
+                _ => return CommandResult::ParseError,
+            },
+            None => 3,
+        };
+
+        match LogicalSolver::new().next_step(board) {
+            Some(step) => CommandResult::HintCommandSuccess(Box::new(step), detail),
+            None => CommandResult::HintCommandNone(board.is_solved()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sudoku_solver::{
+        game::{CandidateSet, Value},
+        logic::Technique,
+    };
+
+    use super::*;
+
+    fn board() -> SudokuBoard {
+        SudokuBoard::parse(
+            "5|3|||7||||\n6|||1|9|5|||\n|9|8|||||6|\n8||||6||||3\n4|||8||3|||1\n7||||2||||6\n|6|||||2|8|\n|||4|1|9|||5\n||||8|||7|9",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn more_lines_with_more_detail() {
+        let lines = [
+            "Try the Hidden Single technique",
+            "Look at box 2",
+            "In box 2, 8 can only go in (1, 6). So (1, 6) is 8.",
+        ];
+        for detail in 1..=3 {
+            match HintCommand.execute(&mut board(), vec![&detail.to_string()]) {
+                CommandResult::HintCommandSuccess(step, d) if d == detail => {
+                    assert_eq!(HintCommand::lines(&step, d), lines[..detail])
+                }
+                _ => panic!("`hint {detail}` should give a hint"),
+            }
+        }
+        // the full deduction by default
+        assert!(matches!(
+            HintCommand.execute(&mut board(), vec![]),
+            CommandResult::HintCommandSuccess(_, 3)
+        ));
+        assert!(matches!(
+            HintCommand.execute(&mut board(), vec!["4"]),
+            CommandResult::ParseError
+        ));
+    }
+
+    #[test]
+    fn region_of_a_step_without_houses() {
+        let mut step = Step::new(Technique::NakedSingle, CandidateSet::single(Value::Four));
+        step.cells.push((1, 3));
+        step.placement = Some((1, 3, Value::Four));
+        assert_eq!(
+            HintCommand::lines(&step, 3),
+            [
+                "Try the Naked Single technique",
+                "Look at (1, 3)",
+                "(1, 3) has 4 as its only possible value. So (1, 3) is 4.",
+            ]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use sudoku_solver::SudokuBoard;
+
 use super::{Command, CommandResult};
 
 pub struct IndicateCommand;
@@ -19,7 +21,7 @@ impl Command for IndicateCommand {
         "Toggles the indicators of available values"
     }
 
-    fn execute(&self, board: &mut crate::game::SudokuBoard, _: Vec<&str>) -> CommandResult {
+    fn execute(&self, board: &mut SudokuBoard, _: Vec<&str>) -> CommandResult {
         let setting = !board.indicator;
         board.indicator = setting;
         CommandResult::IndicateCommandSuccess(setting)
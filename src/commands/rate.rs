@@ -0,0 +1,27 @@
+use sudoku_solver::SudokuBoard;
+
+use super::{Command, CommandResult};
+
+pub struct RateCommand;
+
+impl Command for RateCommand {
+    fn name(&self) -> &'static str {
+        "rate"
+    }
+
+    fn num_args(&self) -> usize {
+        0
+    }
+
+    fn usage(&self) -> &'static str {
+        "Usage: `rate`"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rates the difficulty of the current board by the techniques needed to solve it"
+    }
+
+    fn execute(&self, board: &mut SudokuBoard, _args: Vec<&str>) -> CommandResult {
+        CommandResult::RateCommandSuccess(board.rate())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::game::{RemoveResult, SudokuBoard};
+use sudoku_solver::game::{RemoveResult, SudokuBoard};
 
 use super::{Command, CommandResult};
 
@@ -24,27 +24,17 @@ impl Command for RemoveCommand {
     fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
         let row = match args[0].parse::<usize>() {
             Ok(n) => n,
-            Err(_) => {
-                return CommandResult::ParseError;
-            }
+            Err(_) => return CommandResult::ParseError,
         };
         let col = match args[1].parse::<usize>() {
             Ok(n) => n,
-            Err(_) => {
-                return CommandResult::ParseError;
-            }
+            Err(_) => return CommandResult::ParseError,
         };
 
         match board.remove(row, col) {
-            RemoveResult::Removed(v) => {
-                return CommandResult::RemoveCommandSuccess(v, row, col);
-            }
-            RemoveResult::NoneValue => {
-                return CommandResult::RemoveCommandNoneValue;
-            }
-            RemoveResult::Immutable => {
-                return CommandResult::RemoveCommandImmutable;
-            }
+            RemoveResult::Removed(v) => CommandResult::RemoveCommandSuccess(v, row, col),
+            RemoveResult::NoneValue => CommandResult::RemoveCommandNoneValue,
+            RemoveResult::Immutable => CommandResult::RemoveCommandImmutable,
         }
     }
 }
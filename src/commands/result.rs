@@ -1,4 +1,7 @@
-use crate::game::Value;
+use sudoku_solver::{
+    game::{CheckResult, SolutionCount, SolveStats, SudokuBoard, Value},
+    logic::{Grade, Playback, Rating, Step},
+};
 
 pub enum CommandResult {
     ParseError,
@@ -7,6 +10,7 @@ pub enum CommandResult {
     AddCommandNoneValue,
     AddCommandNotPossible,
     AddCommandAlreadySet,
+    AddCommandIncorrect,
     AddCommandSolved,
 
     RemoveCommandSuccess(Value, usize, usize),
@@ -17,10 +21,28 @@ pub enum CommandResult {
     ChangeCommandNoneValue,
     ChangeCommandNotPossible,
     ChangeCommandImmutable,
+    ChangeCommandIncorrect,
     ChangeCommandSolved,
 
-    SolveCommandSuccess,
-    SolveCommandFailure,
+    SolveCommandSuccess(Option<SolveStats>),
+    SolveCommandOverlay(Box<SudokuBoard>, Option<SolveStats>),
+    SolveCommandFailure(Option<SolveStats>),
+    SolveCommandMistakes(Option<SolveStats>),
+    SolveCommandStuck(Option<SolveStats>),
+    SolveCommandPlayback(Box<Playback>),
 
     IndicateCommandSuccess(bool),
+
+    UniqueCommandSuccess(SolutionCount),
+
+    CheckCommandSuccess(CheckResult),
+    CheckCommandGuard(bool),
+
+    RateCommandSuccess(Rating),
+
+    HintCommandSuccess(Box<Step>, usize),
+    HintCommandNone(bool),
+
+    GenCommandSuccess(Rating),
+    GenCommandFailed(Grade),
 }
@@ -1,40 +1,13 @@
-use core::panic;
-
-use crate::game::{AddResult, PossibleCellValues, SolveResult, SudokuBoard};
+use sudoku_solver::{
+    game::{SolutionCount, SolveResult, SudokuBoard},
+    logic::Playback,
+    solver::{solver_by_name, DfsSolver, Solver},
+};
 
 use super::{Command, CommandResult};
 
 pub struct SolveCommand;
 
-impl SolveCommand {
-    /// DFS algorithm to solve the board.
-    fn solve(&self, board: &mut SudokuBoard) -> SolveResult {
-        if let Some(PossibleCellValues { row, col, values }) = board.pop_possible_value() {
-            for value in values {
-                match board.add(row, col, value) {
-                    AddResult::Solved => return SolveResult::Solved,
-                    AddResult::Added(_) => match self.solve(board) {
-                        SolveResult::Solved => return SolveResult::Solved,
-                        SolveResult::Failed => {
-                            // value didn't lead to a solution, remove it and continue with the next value.
-                            board.remove(row, col);
-                        }
-                    },
-                    // value was not possible, continue with the next value.
-                    AddResult::NotPossible => continue,
-                    _ => panic!("Impossible program state"),
-                };
-            }
-        }
-
-        if board.is_solved() {
-            SolveResult::Solved
-        } else {
-            SolveResult::Failed
-        }
-    }
-}
-
 impl Command for SolveCommand {
     fn name(&self) -> &'static str {
         "s"
@@ -45,19 +18,104 @@ impl Command for SolveCommand {
     }
 
     fn usage(&self) -> &'static str {
-        "Usage: `s`"
+        "Usage: `s <optional solver: dfs, dlx, prop, logic> <optional -v for statistics> <optional -o to keep playing> <optional -p to step through the logical solution>`"
     }
 
     fn description(&self) -> &'static str {
-        "Solves the current board"
+        "Solves the current board, with -o the solution is shown over the board and the board is kept, with -p the logical solver's steps are played back one at a time"
     }
 
-    fn execute(&self, board: &mut SudokuBoard, _args: Vec<&str>) -> CommandResult {
-        match self.solve(board) {
-            SolveResult::Solved => CommandResult::SolveCommandSuccess,
-            // only possible if the supplied config is invalid, boards made at
-            // runtime are always in a valid state and thus solvable.
-            SolveResult::Failed => CommandResult::SolveCommandFailure,
+    fn execute(&self, board: &mut SudokuBoard, args: Vec<&str>) -> CommandResult {
+        let verbose = args.contains(&"-v");
+        let overlay = args.contains(&"-o");
+        let solver: Box<dyn Solver> =
+            match args.iter().find(|&&arg| !["-v", "-o", "-p"].contains(&arg)) {
+                Some(name) => match solver_by_name(name) {
+                    Some(solver) => solver,
+                    None => return CommandResult::ParseError,
+                },
+                None => Box::new(DfsSolver),
+            };
+        if args.contains(&"-p") {
+            return CommandResult::SolveCommandPlayback(Box::new(Playback::new(board)));
         }
+
+        let mut solution = board.clone();
+        let result = if overlay {
+            solution.solve_with(solver.as_ref())
+        } else {
+            board.solve_with(solver.as_ref())
+        };
+
+        match result {
+            SolveResult::Solved(stats) if overlay => {
+                CommandResult::SolveCommandOverlay(Box::new(solution), verbose.then_some(stats))
+            }
+            SolveResult::Solved(stats) => {
+                CommandResult::SolveCommandSuccess(verbose.then_some(stats))
+            }
+            SolveResult::Failed(stats) if board.count_solutions(1) == SolutionCount::AtLeast(1) => {
+                // the solver gave up, e.g. the logical solver found no applicable technique
+                CommandResult::SolveCommandStuck(verbose.then_some(stats))
+            }
+            SolveResult::Failed(stats)
+                if board.givens().count_solutions(1) == SolutionCount::AtLeast(1) =>
+            {
+                // the givens are solvable, so values entered by the user are wrong
+                CommandResult::SolveCommandMistakes(verbose.then_some(stats))
+            }
+            // the givens supplied via a setup file have no solution
+            SolveResult::Failed(stats) => {
+                CommandResult::SolveCommandFailure(verbose.then_some(stats))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> SudokuBoard {
+        SudokuBoard::parse("5|3|||7||||\n6|||1|9|5|||\n|9|8|||||6|").unwrap()
+    }
+
+    #[test]
+    fn statistics_only_with_verbose() {
+        for solver in ["dfs", "dlx", "prop"] {
+            match SolveCommand.execute(&mut board(), vec![solver, "-v", "-o"]) {
+                CommandResult::SolveCommandOverlay(_, Some(stats)) => {
+                    assert!(
+                        stats.nodes > 0 && stats.backtracks <= stats.nodes,
+                        "{stats}"
+                    )
+                }
+                _ => panic!("`s {solver} -v -o` should solve with statistics"),
+            }
+        }
+        assert!(matches!(
+            SolveCommand.execute(&mut board(), vec![]),
+            CommandResult::SolveCommandSuccess(None)
+        ));
+    }
+
+    #[test]
+    fn mistakes_are_told_apart_from_unsolvable_givens() {
+        let mut board = SudokuBoard::parse(
+            "5|3|||7||||\n6|||1|9|5|||\n|9|8|||||6|\n8||||6||||3\n4|||8||3|||1\n7||||2||||6\n|6|||||2|8|\n|||4|1|9|||5\n||||8|||7|9",
+        )
+        .unwrap();
+        // the solution has a 4 in (1, 3)
+        board.add_str(1, 3, "2");
+        assert!(matches!(
+            SolveCommand.execute(&mut board, vec!["-o"]),
+            CommandResult::SolveCommandMistakes(None)
+        ));
+
+        let mut unsolvable = SudokuBoard::parse("1|2|3|4|5|6|7|8|\n||||||||9").unwrap();
+        assert!(matches!(
+            SolveCommand.execute(&mut unsolvable, vec![]),
+            CommandResult::SolveCommandFailure(None)
+        ));
     }
 }
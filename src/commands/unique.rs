@@ -0,0 +1,27 @@
+use sudoku_solver::SudokuBoard;
+
+use super::{Command, CommandResult};
+
+pub struct UniqueCommand;
+
+impl Command for UniqueCommand {
+    fn name(&self) -> &'static str {
+        "u"
+    }
+
+    fn num_args(&self) -> usize {
+        0
+    }
+
+    fn usage(&self) -> &'static str {
+        "Usage: `u`"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks if the current board has a unique solution"
+    }
+
+    fn execute(&self, board: &mut SudokuBoard, _args: Vec<&str>) -> CommandResult {
+        CommandResult::UniqueCommandSuccess(board.count_solutions(2))
+    }
+}
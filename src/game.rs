@@ -1,10 +1,18 @@
 mod board;
-pub use board::SudokuBoard;
+pub use board::{Highlight, Overlay, SudokuBoard};
 mod value;
 pub use value::Value;
 mod cell;
-pub use cell::Cell;
 mod results;
 pub use results::*;
+mod candidate_buckets;
 mod possible_cell_values;
 pub use possible_cell_values::PossibleCellValues;
+mod parse_error;
+pub use parse_error::{ParseError, ParseErrorKind};
+mod house;
+pub use house::House;
+mod conflict;
+pub use conflict::Conflict;
+mod candidate_set;
+pub use candidate_set::{CandidateSet, CandidateSetIter};
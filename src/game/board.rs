@@ -1,23 +1,42 @@
-use std::{collections::BinaryHeap, fmt::Display};
+use std::fmt::Display;
+
+use crate::{
+    logic::{LogicalSolver, Rating},
+    solver::{DfsSolver, Solutions, Solver},
+    units::box_of,
+};
 
 use super::{
+    candidate_buckets::CandidateBuckets,
     cell::Cell,
-    results::{AddResult, ChangeResult, RemoveResult},
+    results::{AddResult, ChangeResult, CheckResult, RemoveResult, SolutionCount, SolveResult},
     value::Value,
-    PossibleCellValues,
+    CandidateSet, Conflict, House, ParseError, ParseErrorKind, PossibleCellValues,
 };
 
 /// SudokuBoard.0\[row]\[col]
+#[derive(Clone)]
 pub struct SudokuBoard {
-    pub board: [[Cell; 9]; 9],
+    pub(crate) board: [[Cell; 9]; 9],
     pub indicator: bool,
 
-    possible_values: BinaryHeap<PossibleCellValues>,
+    possible_values: CandidateBuckets,
+    // values placed in each row, col and 3x3 square
+    row_values: [CandidateSet; 9],
+    col_values: [CandidateSet; 9],
+    square_values: [CandidateSet; 9],
+    // the unique solution of the givens while in guard mode
+    guard_solution: Option<[[Value; 9]; 9]>,
 }
 
 impl SudokuBoard {
     /// Creates a new sudoku board from a vector of strings, where each string is a row of the board. <br>
-    /// If the vector is empty, a blank board is created.
+    /// If the vector is empty, a blank board is created. <br>
+    /// Meant for trusted input, invalid tokens become empty cells and contradictory givens are
+    /// dropped. Use [`SudokuBoard::parse`] for untrusted input.
+    ///
+    /// # Panics
+    /// If there are more than 9 lines or a line has more than 9 `|` separated columns.
     pub fn new<S: AsRef<str>>(lines: Vec<S>) -> Self {
         if lines.len() > 9 {
             panic!("Input file must have a maximum of 9 lines");
@@ -26,7 +45,11 @@ impl SudokuBoard {
         let mut sudoku_board = SudokuBoard {
             board: [[Cell::new(); 9]; 9],
             indicator: true,
-            possible_values: BinaryHeap::new(),
+            possible_values: CandidateBuckets::new(),
+            row_values: [CandidateSet::empty(); 9],
+            col_values: [CandidateSet::empty(); 9],
+            square_values: [CandidateSet::empty(); 9],
+            guard_solution: None,
         };
 
         for (row, line) in lines.iter().enumerate() {
@@ -44,40 +67,172 @@ impl SudokuBoard {
             }
         }
 
-        sudoku_board.update_possible_value_heap();
+        sudoku_board.update_possible_value_buckets();
         sudoku_board
     }
 
-    /// Updates the possible values binary heap.
-    fn update_possible_value_heap(&mut self) {
-        let mut possible_values = BinaryHeap::new();
-        for row in 0..9 {
-            for col in 0..9 {
-                let values = self.board[row][col].possible_values();
-                if !values.is_empty() && self.board[row][col].value == Value::None {
-                    possible_values.push(PossibleCellValues {
-                        row: row + 1,
-                        col: col + 1,
-                        values,
-                    });
+    /// Parses a sudoku board from a setup string, where each line is a row of the board. <br>
+    /// Unlike [`SudokuBoard::new`] it never panics, and it refuses boards
+    /// with invalid digits or contradictory givens, see [`SudokuBoard::validate`].
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (sudoku_board, conflicts) = Self::parse_lenient(input)?;
+
+        if let Some(conflict) = conflicts.first() {
+            let (row, col) = conflict.cells[1];
+            return Err(ParseError::new(
+                row,
+                col,
+                conflict.value.to_string(),
+                ParseErrorKind::ContradictoryGiven,
+            ));
+        }
+
+        Ok(sudoku_board)
+    }
+
+    /// Parses a sudoku board from a setup string like [`SudokuBoard::parse`], but loads boards with
+    /// contradictory givens. <br>
+    /// Of two conflicting givens the latter in reading order is dropped, all conflicts are returned.
+    pub fn parse_lenient(input: &str) -> Result<(Self, Vec<Conflict>), ParseError> {
+        let givens = Self::__parse_givens(input)?;
+        let conflicts = Self::__find_conflicts(&givens);
+
+        let mut sudoku_board = SudokuBoard::new(Vec::<String>::new());
+        for (row, values) in givens.iter().enumerate() {
+            for (col, &val) in values.iter().enumerate() {
+                if val != Value::None && sudoku_board.__value_is_possible(row, col, val) {
+                    sudoku_board.__set_value(row, col, val);
+                    sudoku_board.board[row][col].set_immutable();
                 }
             }
         }
 
-        self.possible_values = possible_values;
+        sudoku_board.update_possible_value_buckets();
+        Ok((sudoku_board, conflicts))
     }
 
-    /// Checks if the board is solved.
-    pub fn is_solved(&self) -> bool {
+    /// Validates a setup string and returns every pair of equal givens sharing a row, column or box.
+    pub fn validate(input: &str) -> Result<Vec<Conflict>, ParseError> {
+        Ok(Self::__find_conflicts(&Self::__parse_givens(input)?))
+    }
+
+    /// Parses the givens of a setup string without checking them against each other.
+    fn __parse_givens(input: &str) -> Result<[[Value; 9]; 9], ParseError> {
+        let mut givens = [[Value::None; 9]; 9];
+
+        for (row, line) in input.lines().enumerate() {
+            if row >= 9 {
+                return Err(ParseError::new(
+                    row + 1,
+                    1,
+                    line,
+                    ParseErrorKind::TooManyRows,
+                ));
+            }
+
+            for (col, token) in line.split('|').enumerate() {
+                if col >= 9 {
+                    return Err(ParseError::new(
+                        row + 1,
+                        col + 1,
+                        token,
+                        ParseErrorKind::TooManyColumns,
+                    ));
+                }
+
+                let token = token.trim();
+                if token.is_empty() {
+                    continue;
+                }
+
+                givens[row][col] = Value::from(token);
+                if givens[row][col] == Value::None {
+                    return Err(ParseError::new(
+                        row + 1,
+                        col + 1,
+                        token,
+                        ParseErrorKind::InvalidDigit,
+                    ));
+                }
+            }
+        }
+
+        Ok(givens)
+    }
+
+    /// Returns every pair of equal values sharing a house.
+    fn __find_conflicts(givens: &[[Value; 9]; 9]) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+
+        for house in House::all() {
+            let cells = house.cells();
+            for (i, &(row1, col1)) in cells.iter().enumerate() {
+                let value = givens[row1 - 1][col1 - 1];
+                if value == Value::None {
+                    continue;
+                }
+
+                for &(row2, col2) in &cells[i + 1..] {
+                    if givens[row2 - 1][col2 - 1] == value {
+                        conflicts.push(Conflict {
+                            value,
+                            house,
+                            cells: [(row1, col1), (row2, col2)],
+                        });
+                    }
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Returns the (row, col) positions of the cells of the 3x3 square containing the cell. <br>
+    /// `row` and `col` and the position bounds are 0..9.
+    fn __square_cells(row: usize, col: usize) -> [(usize, usize); 9] {
+        House::box_of(row + 1, col + 1)
+            .cells()
+            .map(|(row, col)| (row - 1, col - 1))
+    }
+
+    /// Rebuilds the possible values buckets from scratch.
+    fn update_possible_value_buckets(&mut self) {
         for row in 0..9 {
             for col in 0..9 {
-                if self.board[row][col].value == Value::None {
-                    return false;
-                }
+                self.__update_bucket(row, col);
             }
         }
+    }
 
-        true
+    /// Moves a cell into the bucket matching its number of possible values. <br>
+    /// `row` and `col` bounds are 0..9.
+    fn __update_bucket(&mut self, row: usize, col: usize) {
+        let cell = self.board[row][col];
+        let count = if cell.value == Value::None {
+            Some(cell.possible_values.len())
+        } else {
+            None
+        };
+        self.possible_values.update(row * 9 + col, count);
+    }
+
+    /// Updates the buckets of a cell and the 20 cells sharing a row, column or 3x3 square with it. <br>
+    /// `row` and `col` bounds are 0..9.
+    fn __update_buckets_around(&mut self, row: usize, col: usize) {
+        for i in 0..9 {
+            self.__update_bucket(i, col);
+            self.__update_bucket(row, i);
+        }
+
+        for (row, col) in Self::__square_cells(row, col) {
+            self.__update_bucket(row, col);
+        }
+    }
+
+    /// Checks if the board is solved.
+    pub fn is_solved(&self) -> bool {
+        // every empty cell is tracked by the buckets
+        self.possible_values.most_constrained().is_none()
     }
 
     /// Adds a value in string form to the board. <br>
@@ -95,30 +250,15 @@ impl SudokuBoard {
         // don't overwrite existing values and don't set to None
         if val != Value::None {
             if self.board[row][col].value == Value::None {
+                if self.__value_is_incorrect(row, col, val) {
+                    return AddResult::Incorrect;
+                }
+
                 // don't set value if not possible or if it would result in another cell having no possible values
-                if self.board[row][col].possible_values.contains(&val)
+                if self.board[row][col].possible_values.contains(val)
                     && self.__value_is_removable(row, col, val)
                 {
-                    self.board[row][col].value = val;
-                    self.board[row][col].remove_possible_value(val);
-
-                    // update rows and cols
-                    for i in 0..9 {
-                        self.board[i][col].remove_possible_value(val);
-                        self.board[row][i].remove_possible_value(val);
-                    }
-
-                    // update 3x3 square
-                    let row_off = row / 3;
-                    let col_off = col / 3;
-                    for row_count in 0..3 {
-                        for col_count in 0..3 {
-                            self.board[row_off * 3 + row_count][col_off * 3 + col_count]
-                                .remove_possible_value(val);
-                        }
-                    }
-
-                    self.update_possible_value_heap();
+                    self.__set_value(row, col, val);
 
                     if !self.is_solved() {
                         return AddResult::Added(val);
@@ -136,71 +276,68 @@ impl SudokuBoard {
         AddResult::NoneValue
     }
 
-    /// Checks if removing this possible value would result in 0 possible values for any cell.
-    fn __value_is_removable(&self, row: usize, col: usize, val: Value) -> bool {
-        // check rows and cols
-        for i in 0..9 {
-            let col_possible_values = self.board[i][col].possible_values();
-            if col_possible_values.contains(&val) && col_possible_values.len() == 1 {
-                if i != row {
-                    return false;
-                }
-            }
+    /// Checks if guard mode is on and the value disagrees with the solution. <br>
+    /// `row` and `col` bounds are 0..9.
+    fn __value_is_incorrect(&self, row: usize, col: usize, val: Value) -> bool {
+        self.guard_solution
+            .is_some_and(|solution| solution[row][col] != val)
+    }
 
-            let row_possible_values = self.board[row][i].possible_values();
-            if row_possible_values.contains(&val) && row_possible_values.len() == 1 {
-                if i != col {
-                    return false;
-                }
-            }
+    /// Sets the value of a cell and removes it from the possible values of its row, column and 3x3 square. <br>
+    /// `row` and `col` bounds are 0..9.
+    fn __set_value(&mut self, row: usize, col: usize, val: Value) {
+        self.board[row][col].value = val;
+        self.board[row][col].remove_possible_value(val);
+        self.row_values[row].insert(val);
+        self.col_values[col].insert(val);
+        self.square_values[box_of(row * 9 + col)].insert(val);
+
+        // update rows and cols
+        for i in 0..9 {
+            self.board[i][col].remove_possible_value(val);
+            self.board[row][i].remove_possible_value(val);
         }
 
-        // check 3x3 square
-        let row_off = row / 3;
-        let col_off = col / 3;
-        for row_count in 0..3 {
-            for col_count in 0..3 {
-                let _row = row_off * 3 + row_count;
-                let _col = col_off * 3 + col_count;
-                let possible_values =
-                    self.board[_row][_col].possible_values();
-                if possible_values.contains(&val) && possible_values.len() == 1 {
-                    if _row != row || _col != col {
-                        return false;
-                    }
-                }
-            }
+        // update 3x3 square
+        for (row, col) in Self::__square_cells(row, col) {
+            self.board[row][col].remove_possible_value(val);
         }
 
-        true
+        self.__update_buckets_around(row, col);
     }
 
-    /// Checks if the value at the given row and column is possible.
-    fn __value_is_possible(&self, row: usize, col: usize, val: Value) -> bool {
+    /// Checks if removing this possible value would result in 0 possible values for any cell.
+    fn __value_is_removable(&self, row: usize, col: usize, val: Value) -> bool {
         // check rows and cols
         for i in 0..9 {
-            if self.board[i][col].value == val {
+            if self.board[i][col].possible_values().only() == Some(val) && i != row {
                 return false;
             }
-            if self.board[row][i].value == val {
+
+            if self.board[row][i].possible_values().only() == Some(val) && i != col {
                 return false;
             }
         }
 
         // check 3x3 square
-        let row_off = row / 3;
-        let col_off = col / 3;
-        for row_count in 0..3 {
-            for col_count in 0..3 {
-                if self.board[row_off * 3 + row_count][col_off * 3 + col_count].value == val {
-                    return false;
-                }
+        for (_row, _col) in Self::__square_cells(row, col) {
+            if self.board[_row][_col].possible_values().only() == Some(val)
+                && (_row != row || _col != col)
+            {
+                return false;
             }
         }
 
         true
     }
 
+    /// Checks if the value at the given row and column is possible.
+    fn __value_is_possible(&self, row: usize, col: usize, val: Value) -> bool {
+        // check rows, cols and 3x3 square
+        !(self.row_values[row] | self.col_values[col] | self.square_values[box_of(row * 9 + col)])
+            .contains(val)
+    }
+
     /// Removes a value from the board. <br>
     /// row and col bounds are 1..=9.
     pub fn remove(&mut self, row: usize, col: usize) -> RemoveResult {
@@ -215,6 +352,9 @@ impl SudokuBoard {
                 let v = self.board[row][col].value;
                 self.board[row][col].value = Value::None;
                 self.board[row][col].add_possible_value(v);
+                self.row_values[row].remove(v);
+                self.col_values[col].remove(v);
+                self.square_values[box_of(row * 9 + col)].remove(v);
 
                 // update rows and cols
                 for i in 0..9 {
@@ -227,19 +367,13 @@ impl SudokuBoard {
                 }
 
                 // update 3x3 square
-                let row_off = row / 3;
-                let col_off = col / 3;
-                for row_count in 0..3 {
-                    for col_count in 0..3 {
-                        let row_offset = row_off * 3 + row_count;
-                        let col_offset = col_off * 3 + col_count;
-                        if self.__value_is_possible(row_offset, col_offset, v) {
-                            self.board[row_offset][col_offset].add_possible_value(v);
-                        }
+                for (_row, _col) in Self::__square_cells(row, col) {
+                    if self.__value_is_possible(_row, _col, v) {
+                        self.board[_row][_col].add_possible_value(v);
                     }
                 }
 
-                self.update_possible_value_heap();
+                self.__update_buckets_around(row, col);
 
                 return RemoveResult::Removed(v);
             }
@@ -250,6 +384,21 @@ impl SudokuBoard {
         RemoveResult::Immutable
     }
 
+    /// Removes a possible value of an empty cell, e.g. after a logical deduction. <br>
+    /// Returns false if the value wasn't possible. `row` and `col` bounds are 1..=9.
+    pub fn eliminate(&mut self, row: usize, col: usize, val: Value) -> bool {
+        let (row, col) = (row - 1, col - 1);
+        if self.board[row][col].value != Value::None
+            || !self.board[row][col].possible_values.contains(val)
+        {
+            return false;
+        }
+
+        self.board[row][col].remove_possible_value(val);
+        self.__update_bucket(row, col);
+        true
+    }
+
     /// Changes the value in string form of a cell. <br>
     /// row and col bounds are 1..=9.
     pub fn change_str<S: AsRef<str>>(&mut self, row: usize, col: usize, val: S) -> ChangeResult {
@@ -259,13 +408,29 @@ impl SudokuBoard {
     /// Changes the value of a cell. <br>
     /// row and col bounds are 1..=9.
     pub fn change(&mut self, row: usize, col: usize, val: Value) -> ChangeResult {
+        // changing to an empty cell is a removal, refuse it before the old value is removed
+        if val == Value::None {
+            return ChangeResult::NoneValue;
+        }
+
+        // check guard mode before removing, the old value may be incorrect as well
+        if self.board[row - 1][col - 1].mutable
+            && self.board[row - 1][col - 1].value != Value::None
+            && self.__value_is_incorrect(row - 1, col - 1, val)
+        {
+            return ChangeResult::Incorrect;
+        }
+
         match self.remove(row, col) {
             RemoveResult::Removed(rem_v) => match self.add(row, col, val) {
                 AddResult::Added(add_v) => ChangeResult::Changed(rem_v, add_v),
-                AddResult::AlreadySet | AddResult::NoneValue => panic!("Impossible program state"),
+                AddResult::AlreadySet | AddResult::NoneValue | AddResult::Incorrect => {
+                    panic!("Impossible program state")
+                }
                 AddResult::NotPossible => {
-                    // if adding failed because the new value is not legal, restore the old value
-                    self.add(row, col, rem_v);
+                    // if adding failed because the new value is not legal, restore the old value,
+                    // bypassing guard mode as the old value may disagree with the solution
+                    self.__set_value(row - 1, col - 1, rem_v);
                     ChangeResult::NotPossible
                 }
                 AddResult::Solved => ChangeResult::Solved,
@@ -275,25 +440,194 @@ impl SudokuBoard {
         }
     }
 
-    /// Returns the PossibleCellValue with the least possible values.
-    pub fn pop_possible_value(&mut self) -> Option<PossibleCellValues> {
-        self.possible_values.pop()
+    /// Solves the board in place using the [`DfsSolver`]. <br>
+    /// On failure the board is left in its original state.
+    pub fn solve(&mut self) -> SolveResult {
+        DfsSolver.solve(self)
+    }
+
+    /// Solves the board in place using the given solver. <br>
+    /// On failure the board is left in its original state.
+    pub fn solve_with(&mut self, solver: &dyn Solver) -> SolveResult {
+        solver.solve(self)
+    }
+
+    /// Returns the solution of the board, solved by the [`DfsSolver`] on a copy of the board. <br>
+    /// The board itself is not modified, `None` is returned if the board can't be solved.
+    pub fn solution(&self) -> Option<SudokuBoard> {
+        let mut solution = self.clone();
+        match solution.solve() {
+            SolveResult::Solved(_) => Some(solution),
+            SolveResult::Failed(_) => None,
+        }
+    }
+
+    /// Rates the difficulty of the board by solving a copy with the [`LogicalSolver`]. <br>
+    /// The score is the rating of the hardest step, see [`Rating`].
+    pub fn rate(&self) -> Rating {
+        let mut copy = self.__clone_without_guard();
+        let steps = LogicalSolver::new().run(&mut copy);
+        Rating::new(&steps, copy.is_solved())
+    }
+
+    /// Returns a view of the board showing the values of `overlay` in its empty cells. <br>
+    /// Use it with the [`SudokuBoard::solution`] to compare the board with the answer.
+    pub fn overlay<'a>(&'a self, overlay: &'a SudokuBoard) -> Overlay<'a> {
+        Overlay {
+            board: self,
+            overlay,
+        }
+    }
+
+    /// Returns a view of the board printing the cells at the (row, col) positions in reverse video. <br>
+    /// The position bounds are 1..=9.
+    pub fn highlight<'a>(&'a self, cells: &'a [(usize, usize)]) -> Highlight<'a> {
+        Highlight { board: self, cells }
+    }
+
+    /// Counts the solutions of the board, stopping once `limit` solutions are found. <br>
+    /// The board itself is not modified, use a `limit` of 2 to check for a unique solution.
+    pub fn count_solutions(&self, limit: usize) -> SolutionCount {
+        let count = DfsSolver.count_solutions(&mut self.__clone_without_guard(), limit);
+        if count >= limit {
+            SolutionCount::AtLeast(limit)
+        } else {
+            SolutionCount::Exactly(count)
+        }
+    }
+
+    /// Returns a lazy iterator over all solutions of the board. <br>
+    /// The board itself is not modified, use [`Solutions::limit`] to cap the number of solutions.
+    pub fn solutions(&self) -> Solutions {
+        Solutions::new(self)
+    }
+
+    /// Returns a copy of the board with only the givens (immutable values).
+    pub fn givens(&self) -> SudokuBoard {
+        let mut givens = SudokuBoard::new(Vec::<String>::new());
+        for row in 0..9 {
+            for col in 0..9 {
+                let cell = self.board[row][col];
+                if !cell.mutable {
+                    givens.__set_value(row, col, cell.value);
+                    givens.board[row][col].set_immutable();
+                }
+            }
+        }
+        givens
+    }
+
+    /// Checks the values entered on the board against the unique solution of the givens. <br>
+    /// The board itself is not modified.
+    pub fn check(&self) -> CheckResult {
+        let solution = match self.__givens_solution() {
+            Ok(solution) => solution,
+            Err(result) => return result,
+        };
+
+        let mut mistakes = Vec::new();
+        for (row, solution_row) in solution.iter().enumerate() {
+            for (col, &solution_value) in solution_row.iter().enumerate() {
+                let value = self.board[row][col].value;
+                if value != Value::None && value != solution_value {
+                    mistakes.push((row + 1, col + 1));
+                }
+            }
+        }
+        CheckResult::Mistakes(mistakes)
+    }
+
+    /// Turns guard mode on or off, in guard mode values disagreeing with the unique solution
+    /// of the givens are refused by [`SudokuBoard::add`] and [`SudokuBoard::change`]. <br>
+    /// Guard mode can't be turned on if the givens don't have a unique solution, the reason is returned instead.
+    pub fn set_guard(&mut self, guard: bool) -> Result<(), CheckResult> {
+        self.guard_solution = if guard {
+            Some(self.__givens_solution()?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    /// Checks if guard mode is on.
+    pub fn is_guarded(&self) -> bool {
+        self.guard_solution.is_some()
+    }
+
+    /// Returns a copy of the board with guard mode turned off, for searches that try incorrect values.
+    pub(crate) fn __clone_without_guard(&self) -> SudokuBoard {
+        let mut board = self.clone();
+        board.guard_solution = None;
+        board
+    }
+
+    /// Returns the unique solution of the givens, or why there is none.
+    fn __givens_solution(&self) -> Result<[[Value; 9]; 9], CheckResult> {
+        let mut solutions = self.givens().solutions().limit(2);
+        let Some(solution) = solutions.next() else {
+            return Err(CheckResult::NoSolution);
+        };
+        if solutions.next().is_some() {
+            return Err(CheckResult::MultipleSolutions);
+        }
+        Ok(solution.board.map(|row| row.map(|cell| cell.value)))
+    }
+
+    /// Returns the value of a cell. <br>
+    /// row and col bounds are 1..=9.
+    pub fn value(&self, row: usize, col: usize) -> Value {
+        self.board[row - 1][col - 1].value
+    }
+
+    /// Returns the possible values (candidates) of a cell, empty if the cell is set. <br>
+    /// row and col bounds are 1..=9.
+    pub fn candidates(&self, row: usize, col: usize) -> CandidateSet {
+        self.board[row - 1][col - 1].possible_values()
+    }
+
+    /// Returns the empty cell with the least possible values, ties are broken in reading order. <br>
+    /// A cell without possible values is returned first, as the board can't be solved from here.
+    pub fn most_constrained_cell(&self) -> Option<PossibleCellValues> {
+        self.possible_values
+            .most_constrained()
+            .map(|index| PossibleCellValues {
+                row: index / 9 + 1,
+                col: index % 9 + 1,
+                values: self.board[index / 9][index % 9].possible_values(),
+            })
     }
 
     /// Returns a string representation of the possible values of a cell for the values val_off1 through val_off3. <br>
+    /// `overlay` is the value to show instead of the possible values of an empty cell.
     fn __get_print_row_values(
         &self,
         cell: Cell,
+        overlay: Value,
         val_off1: usize,
         val_off2: usize,
         val_off3: usize,
     ) -> String {
-        if cell.value == Value::None && self.indicator {
+        if cell.value == Value::None && overlay != Value::None {
+            if val_off1 == 3 {
+                // print the overlay value in brackets on the middle row
+                format!(" ({overlay}) ")
+            } else {
+                "     ".to_string()
+            }
+        } else if cell.value == Value::None && self.indicator {
+            let sup_str = |val_off: usize| {
+                let val = Value::from_usize(val_off + 1);
+                if cell.possible_values.contains(val) {
+                    val.sup_str()
+                } else {
+                    Value::None.sup_str()
+                }
+            };
             format!(
                 "{} {} {}",
-                cell.possible_values[val_off1].sup_str(),
-                cell.possible_values[val_off2].sup_str(),
-                cell.possible_values[val_off3].sup_str()
+                sup_str(val_off1),
+                sup_str(val_off2),
+                sup_str(val_off3)
             )
         } else if val_off1 == 3 {
             // print the value if on the middle row
@@ -304,11 +638,15 @@ impl SudokuBoard {
     }
 
     /// prints a row. <br>
+    /// Empty cells show the value of the `overlay` board if there is one,
+    /// `highlighted` (row, col) positions are printed in reverse video.
     fn __print_row(
         &self,
         f: &mut std::fmt::Formatter,
         i: usize,
         row: [Cell; 9],
+        overlay: Option<&SudokuBoard>,
+        highlighted: &[(usize, usize)],
     ) -> std::fmt::Result {
         for (val_off1, val_off2, val_off3) in [(0, 1, 2), (3, 4, 5), (6, 7, 8)] {
             let border = if val_off1 == 3 {
@@ -319,13 +657,29 @@ impl SudokuBoard {
 
             write!(f, "{border}")?;
 
+            let print_value = |col: usize| {
+                let overlay_value = overlay.map_or(Value::None, |o| o.board[i - 1][col].value);
+                let value = self.__get_print_row_values(
+                    row[col],
+                    overlay_value,
+                    val_off1,
+                    val_off2,
+                    val_off3,
+                );
+                if highlighted.contains(&(i, col + 1)) {
+                    format!("\x1b[7m{value}\x1b[0m")
+                } else {
+                    value
+                }
+            };
+
             for (row1, row2, row3) in [(0, 1, 2), (3, 4, 5), (6, 7, 8)] {
                 write!(
                     f,
                     " {} │ {} │ {} ║",
-                    self.__get_print_row_values(row[row1], val_off1, val_off2, val_off3),
-                    self.__get_print_row_values(row[row2], val_off1, val_off2, val_off3),
-                    self.__get_print_row_values(row[row3], val_off1, val_off2, val_off3)
+                    print_value(row1),
+                    print_value(row2),
+                    print_value(row3)
                 )?;
             }
             writeln!(f)?;
@@ -362,27 +716,260 @@ macro_rules! print_border {
     };
 }
 
-impl Display for SudokuBoard {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl SudokuBoard {
+    /// Prints the board, empty cells show the value of the `overlay` board if there is one.
+    /// `highlighted` (row, col) positions are printed in reverse video.
+    fn __fmt(
+        &self,
+        f: &mut std::fmt::Formatter,
+        overlay: Option<&SudokuBoard>,
+        highlighted: &[(usize, usize)],
+    ) -> std::fmt::Result {
         print_border!(f, head);
-        self.__print_row(f, 1, self.board[0])?;
+        self.__print_row(f, 1, self.board[0], overlay, highlighted)?;
         print_border!(f, thin);
-        self.__print_row(f, 2, self.board[1])?;
+        self.__print_row(f, 2, self.board[1], overlay, highlighted)?;
         print_border!(f, thin);
-        self.__print_row(f, 3, self.board[2])?;
+        self.__print_row(f, 3, self.board[2], overlay, highlighted)?;
         print_border!(f, thick);
-        self.__print_row(f, 4, self.board[3])?;
+        self.__print_row(f, 4, self.board[3], overlay, highlighted)?;
         print_border!(f, thin);
-        self.__print_row(f, 5, self.board[4])?;
+        self.__print_row(f, 5, self.board[4], overlay, highlighted)?;
         print_border!(f, thin);
-        self.__print_row(f, 6, self.board[5])?;
+        self.__print_row(f, 6, self.board[5], overlay, highlighted)?;
         print_border!(f, thick);
-        self.__print_row(f, 7, self.board[6])?;
+        self.__print_row(f, 7, self.board[6], overlay, highlighted)?;
         print_border!(f, thin);
-        self.__print_row(f, 8, self.board[7])?;
+        self.__print_row(f, 8, self.board[7], overlay, highlighted)?;
         print_border!(f, thin);
-        self.__print_row(f, 9, self.board[8])?;
+        self.__print_row(f, 9, self.board[8], overlay, highlighted)?;
         print_border!(f, tail);
         Ok(())
     }
 }
+
+impl Display for SudokuBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.__fmt(f, None, &[])
+    }
+}
+
+/// A board with the values of another board, usually its solution, shown in its empty cells. <br>
+/// Created by [`SudokuBoard::overlay`].
+pub struct Overlay<'a> {
+    board: &'a SudokuBoard,
+    overlay: &'a SudokuBoard,
+}
+
+impl Display for Overlay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.board.__fmt(f, Some(self.overlay), &[])
+    }
+}
+
+/// A board with some cells printed in reverse video. <br>
+/// Created by [`SudokuBoard::highlight`].
+pub struct Highlight<'a> {
+    board: &'a SudokuBoard,
+    cells: &'a [(usize, usize)],
+}
+
+impl Display for Highlight<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.board.__fmt(f, None, self.cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{board, TWO_SOLUTIONS, UNIQUE};
+
+    const UNIQUE_SETUP: &str = "5|3|||7||||\n6|||1|9|5|||\n|9|8|||||6|";
+
+    fn parse_error(input: &str) -> ParseError {
+        match SudokuBoard::parse(input) {
+            Ok(_) => panic!("`{input}` should not parse"),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn parse_accepts_a_valid_setup() {
+        let board = SudokuBoard::parse("5|3|||7||||\n6|||1|9|5|||\n\n|9|8").unwrap();
+        assert_eq!(board.value(1, 1), Value::Five);
+        assert_eq!(board.value(2, 6), Value::Five);
+        assert_eq!(board.value(4, 2), Value::Nine);
+        assert_eq!(board.value(3, 1), Value::None);
+    }
+
+    #[test]
+    fn parse_rejects_too_many_rows() {
+        let error = parse_error(&format!("{}1", "|\n".repeat(9)));
+        assert_eq!(error.kind, ParseErrorKind::TooManyRows);
+        assert_eq!((error.line, error.column), (10, 1));
+    }
+
+    #[test]
+    fn parse_rejects_too_many_columns() {
+        let error = parse_error("\n1|2|3|4|5|6|7|8|9|1");
+        assert_eq!(error.kind, ParseErrorKind::TooManyColumns);
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.token, "1");
+    }
+
+    #[test]
+    fn parse_rejects_invalid_digits() {
+        for token in ["0", "10", "x", "-1"] {
+            let error = parse_error(&format!("1|2|{token}"));
+            assert_eq!(error.kind, ParseErrorKind::InvalidDigit);
+            assert_eq!((error.line, error.column), (1, 3));
+            assert_eq!(error.token, token);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_contradictory_givens() {
+        let error = parse_error("5\n\n||5");
+        assert_eq!(error.kind, ParseErrorKind::ContradictoryGiven);
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.token, "5");
+    }
+
+    #[test]
+    fn parse_lenient_drops_the_latter_contradictory_given() {
+        let (board, conflicts) = SudokuBoard::parse_lenient("5|||5").unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].cells, [(1, 1), (1, 4)]);
+        assert_eq!(board.value(1, 1), Value::Five);
+        assert_eq!(board.value(1, 4), Value::None);
+    }
+
+    #[test]
+    fn count_solutions_of_a_unique_puzzle() {
+        let board = board(UNIQUE);
+        assert_eq!(board.count_solutions(2), SolutionCount::Exactly(1));
+        assert_eq!(board.count_solutions(1), SolutionCount::AtLeast(1));
+        assert_eq!(board.value(1, 3), Value::None);
+    }
+
+    #[test]
+    fn count_solutions_stops_at_the_limit() {
+        let board = board(TWO_SOLUTIONS);
+        assert_eq!(board.count_solutions(2), SolutionCount::AtLeast(2));
+        assert_eq!(board.count_solutions(3), SolutionCount::Exactly(2));
+        assert_eq!(
+            SudokuBoard::new(Vec::<String>::new()).count_solutions(50),
+            SolutionCount::AtLeast(50)
+        );
+    }
+
+    #[test]
+    fn count_solutions_without_a_solution() {
+        // (1, 9) can't hold a value, as 9 is in its column
+        let board = SudokuBoard::parse("1|2|3|4|5|6|7|8|\n||||||||9").unwrap();
+        assert_eq!(board.count_solutions(2), SolutionCount::Exactly(0));
+        assert!(!board.count_solutions(2).is_unique());
+    }
+
+    #[test]
+    fn change_to_an_empty_cell_is_refused() {
+        for guard in [false, true] {
+            let mut board = board(UNIQUE);
+            board.set_guard(guard).unwrap();
+            assert!(matches!(board.add(1, 3, Value::Four), AddResult::Added(_)));
+            assert!(matches!(
+                board.change_str(1, 3, "0"),
+                ChangeResult::NoneValue
+            ));
+            assert_eq!(board.value(1, 3), Value::Four);
+        }
+    }
+
+    #[test]
+    fn check_reports_mistakes() {
+        let mut board = board(UNIQUE);
+        // the solution has a 4 in (1, 3) and a 6 in (1, 4)
+        board.add(1, 3, Value::Two);
+        board.add(1, 4, Value::Six);
+        assert_eq!(board.check(), CheckResult::Mistakes(vec![(1, 3)]));
+        assert_eq!(board.givens().check(), CheckResult::Mistakes(Vec::new()));
+        assert_eq!(
+            crate::testing::board(TWO_SOLUTIONS).check(),
+            CheckResult::MultipleSolutions
+        );
+    }
+
+    #[test]
+    fn guard_mode_refuses_incorrect_values() {
+        let mut board = board(UNIQUE);
+        board.set_guard(true).unwrap();
+        assert!(matches!(board.add(1, 3, Value::Two), AddResult::Incorrect));
+        assert_eq!(board.value(1, 3), Value::None);
+
+        assert!(matches!(board.add(1, 3, Value::Four), AddResult::Added(_)));
+        assert!(matches!(
+            board.change(1, 3, Value::Two),
+            ChangeResult::Incorrect
+        ));
+        assert_eq!(board.value(1, 3), Value::Four);
+
+        board.set_guard(false).unwrap();
+        assert!(matches!(
+            board.change(1, 3, Value::Two),
+            ChangeResult::Changed(Value::Four, Value::Two)
+        ));
+    }
+
+    #[test]
+    fn guard_mode_keeps_an_incorrect_value_when_a_change_is_not_possible() {
+        let mut board = board(UNIQUE);
+        // the 2 in (1, 3) is incorrect, entered before guard mode was turned on
+        board.add(1, 3, Value::Two);
+        board.add(1, 6, Value::Four);
+        board.set_guard(true).unwrap();
+        assert!(matches!(
+            board.change(1, 3, Value::Four),
+            ChangeResult::NotPossible
+        ));
+        assert_eq!(board.value(1, 3), Value::Two);
+        assert!(!board.candidates(1, 1).contains(Value::Two));
+    }
+
+    #[test]
+    fn validate_reports_every_conflict() {
+        // three equal givens in a row are three pairs
+        let conflicts = SudokuBoard::validate("7|||7|||||7").unwrap();
+        let cells = conflicts
+            .iter()
+            .map(|conflict| conflict.cells)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            [[(1, 1), (1, 4)], [(1, 1), (1, 9)], [(1, 4), (1, 9)]]
+        );
+        assert!(conflicts
+            .iter()
+            .all(|conflict| conflict.value == Value::Seven && conflict.house == House::Row(1)));
+    }
+
+    #[test]
+    fn validate_reports_box_only_conflicts() {
+        let conflicts = SudokuBoard::validate("3\n|||\n||3").unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].house, House::Box(1));
+        assert_eq!(conflicts[0].cells, [(1, 1), (3, 3)]);
+        assert!(SudokuBoard::validate(UNIQUE_SETUP).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_lenient_keeps_the_first_of_several_conflicting_givens() {
+        let (board, conflicts) = SudokuBoard::parse_lenient("3|||3\n\n||3").unwrap();
+        // (1, 4) conflicts in its row, (3, 3) in its box
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(board.value(1, 1), Value::Three);
+        assert_eq!(board.value(1, 4), Value::None);
+        assert_eq!(board.value(3, 3), Value::None);
+        assert_eq!(board.givens().value(1, 1), Value::Three);
+    }
+}
@@ -0,0 +1,104 @@
+/// Unset cells grouped by their number of possible values, used to find the most constrained cell. <br>
+/// Cells are indexed `row * 9 + col` with `row` and `col` bounds 0..9, each bucket is a bitset of cells.
+#[derive(Clone, Copy)]
+pub struct CandidateBuckets {
+    buckets: [u128; 10],
+    counts: [u8; 81],
+}
+
+impl CandidateBuckets {
+    /// Marks a cell as not tracked.
+    const UNTRACKED: u8 = u8::MAX;
+
+    /// Creates buckets without any tracked cells.
+    pub fn new() -> Self {
+        CandidateBuckets {
+            buckets: [0; 10],
+            counts: [Self::UNTRACKED; 81],
+        }
+    }
+
+    /// Moves the cell into the bucket for `count` possible values, `None` stops tracking the cell.
+    pub fn update(&mut self, index: usize, count: Option<usize>) {
+        let old = self.counts[index];
+        let new = count.map_or(Self::UNTRACKED, |count| count as u8);
+        if old == new {
+            return;
+        }
+
+        if old != Self::UNTRACKED {
+            self.buckets[old as usize] &= !(1 << index);
+        }
+        if new != Self::UNTRACKED {
+            self.buckets[new as usize] |= 1 << index;
+        }
+        self.counts[index] = new;
+    }
+
+    /// Returns the index of the tracked cell with the least possible values. <br>
+    /// Cells without possible values come first, ties are broken in reading order.
+    pub fn most_constrained(&self) -> Option<usize> {
+        self.buckets
+            .iter()
+            .find(|&&bucket| bucket != 0)
+            .map(|bucket| bucket.trailing_zeros() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{AddResult, SudokuBoard, Value},
+        testing::{board, UNIQUE},
+    };
+
+    #[test]
+    fn most_constrained_prefers_fewer_values_then_reading_order() {
+        let mut buckets = CandidateBuckets::new();
+        assert_eq!(buckets.most_constrained(), None);
+
+        buckets.update(40, Some(3));
+        buckets.update(80, Some(2));
+        buckets.update(10, Some(2));
+        assert_eq!(buckets.most_constrained(), Some(10));
+
+        buckets.update(10, None);
+        assert_eq!(buckets.most_constrained(), Some(80));
+        buckets.update(40, Some(0));
+        assert_eq!(buckets.most_constrained(), Some(40));
+        buckets.update(40, Some(9));
+        buckets.update(80, Some(9));
+        assert_eq!(buckets.most_constrained(), Some(40));
+    }
+
+    /// Finds the most constrained cell by scanning all cells.
+    fn scan(board: &SudokuBoard) -> Option<(usize, usize, usize)> {
+        (1..=9)
+            .flat_map(|row| (1..=9).map(move |col| (row, col)))
+            .filter(|&(row, col)| board.value(row, col) == Value::None)
+            .map(|(row, col)| (row, col, board.candidates(row, col).len()))
+            .min_by_key(|&(row, col, count)| (count, row, col))
+    }
+
+    #[test]
+    fn stays_in_sync_after_add_and_remove() {
+        let mut board = board(UNIQUE);
+        let solution = board.solution().unwrap();
+        let moves = [(1, 3), (5, 5), (9, 1), (2, 2), (7, 6)];
+
+        for &(row, col) in &moves {
+            assert!(matches!(
+                board.add(row, col, solution.value(row, col)),
+                AddResult::Added(_)
+            ));
+            let cell = board.most_constrained_cell().unwrap();
+            assert_eq!(Some((cell.row, cell.col, cell.values.len())), scan(&board));
+        }
+        for &(row, col) in moves.iter().rev() {
+            board.remove(row, col);
+            let cell = board.most_constrained_cell().unwrap();
+            assert_eq!(Some((cell.row, cell.col, cell.values.len())), scan(&board));
+        }
+    }
+}
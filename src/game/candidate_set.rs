@@ -0,0 +1,284 @@
+use std::{
+    fmt::Display,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign},
+};
+
+use super::Value;
+
+/// A set of the values 1 through 9, stored as a bitmask where bit `n - 1` marks the value `n`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct CandidateSet(u16);
+
+impl CandidateSet {
+    const ALL_BITS: u16 = 0b1_1111_1111;
+
+    /// Creates an empty set.
+    pub const fn empty() -> Self {
+        CandidateSet(0)
+    }
+
+    /// Creates a set containing all values 1 through 9.
+    pub const fn full() -> Self {
+        CandidateSet(Self::ALL_BITS)
+    }
+
+    /// Creates a set from a bitmask, bits above the 9th are ignored.
+    pub const fn from_bits(bits: u16) -> Self {
+        CandidateSet(bits & Self::ALL_BITS)
+    }
+
+    /// Returns the bitmask of the set.
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Creates a set containing a single value, empty for `Value::None`.
+    pub fn single(val: Value) -> Self {
+        match val {
+            Value::None => Self::empty(),
+            _ => CandidateSet(1 << (val.to_usize() - 1)),
+        }
+    }
+
+    /// Checks if the set contains the value.
+    pub fn contains(&self, val: Value) -> bool {
+        val != Value::None && self.0 & Self::single(val).0 != 0
+    }
+
+    /// Adds the value to the set.
+    pub fn insert(&mut self, val: Value) {
+        self.0 |= Self::single(val).0;
+    }
+
+    /// Removes the value from the set.
+    pub fn remove(&mut self, val: Value) {
+        self.0 &= !Self::single(val).0;
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Checks if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the only value of the set, `None` if it has zero or multiple values.
+    pub fn only(&self) -> Option<Value> {
+        if self.len() == 1 {
+            self.first()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest value of the set.
+    pub fn first(&self) -> Option<Value> {
+        self.iter().next()
+    }
+
+    /// Returns the union of both sets.
+    pub fn union(&self, other: CandidateSet) -> Self {
+        CandidateSet(self.0 | other.0)
+    }
+
+    /// Returns the values contained in both sets.
+    pub fn intersection(&self, other: CandidateSet) -> Self {
+        CandidateSet(self.0 & other.0)
+    }
+
+    /// Returns the values of this set that are not in the other set.
+    pub fn difference(&self, other: CandidateSet) -> Self {
+        CandidateSet(self.0 & !other.0)
+    }
+
+    /// Checks if all values of this set are in the other set.
+    pub fn is_subset(&self, other: CandidateSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns an iterator over the values in ascending order.
+    pub fn iter(&self) -> CandidateSetIter {
+        CandidateSetIter(self.0)
+    }
+}
+
+/// Iterator over the values of a [`CandidateSet`] in ascending order.
+pub struct CandidateSetIter(u16);
+
+impl Iterator for CandidateSetIter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(Value::from_usize(bit + 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CandidateSetIter {}
+
+impl IntoIterator for CandidateSet {
+    type Item = Value;
+    type IntoIter = CandidateSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Value> for CandidateSet {
+    fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> Self {
+        let mut set = CandidateSet::empty();
+        for val in iter {
+            set.insert(val);
+        }
+        set
+    }
+}
+
+impl BitOr for CandidateSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for CandidateSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for CandidateSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for CandidateSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for CandidateSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for CandidateSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for CandidateSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        CandidateSet(!self.0 & Self::ALL_BITS)
+    }
+}
+
+impl Display for CandidateSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, val) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{val}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(values: &[usize]) -> CandidateSet {
+        values.iter().map(|&n| Value::from_usize(n)).collect()
+    }
+
+    #[test]
+    fn insert_remove_and_contains() {
+        let mut values = CandidateSet::empty();
+        assert!(values.is_empty());
+        values.insert(Value::Three);
+        values.insert(Value::Nine);
+        values.insert(Value::Three);
+        assert_eq!(values.len(), 2);
+        assert!(values.contains(Value::Three) && values.contains(Value::Nine));
+        assert!(!values.contains(Value::One) && !values.contains(Value::None));
+
+        values.remove(Value::Three);
+        values.remove(Value::One);
+        assert_eq!(values, set(&[9]));
+        assert_eq!(values.only(), Some(Value::Nine));
+
+        values.insert(Value::None);
+        assert_eq!(values, set(&[9]));
+    }
+
+    #[test]
+    fn full_and_bits() {
+        assert_eq!(CandidateSet::full().len(), 9);
+        assert_eq!(CandidateSet::from_bits(u16::MAX), CandidateSet::full());
+        assert_eq!(set(&[1, 3]).bits(), 0b101);
+        assert_eq!(CandidateSet::single(Value::None), CandidateSet::empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[1, 2, 3]);
+        let b = set(&[3, 4]);
+        assert_eq!(a | b, set(&[1, 2, 3, 4]));
+        assert_eq!(a & b, set(&[3]));
+        assert_eq!(a - b, set(&[1, 2]));
+        assert_eq!(!a, set(&[4, 5, 6, 7, 8, 9]));
+        assert_eq!(a.union(b), a | b);
+        assert_eq!(a.intersection(b), a & b);
+        assert_eq!(a.difference(b), a - b);
+        assert!(set(&[1, 3]).is_subset(a));
+        assert!(!b.is_subset(a));
+
+        let mut c = a;
+        c |= b;
+        c &= set(&[2, 3, 4, 5]);
+        c -= set(&[3]);
+        assert_eq!(c, set(&[2, 4]));
+    }
+
+    #[test]
+    fn iterates_in_ascending_order() {
+        let values = set(&[7, 2, 5]);
+        assert_eq!(
+            values.iter().collect::<Vec<_>>(),
+            vec![Value::Two, Value::Five, Value::Seven]
+        );
+        assert_eq!(values.iter().len(), 3);
+        assert_eq!(values.first(), Some(Value::Two));
+        assert_eq!(values.only(), None);
+        assert_eq!(values.to_string(), "{2, 5, 7}");
+        assert_eq!(CandidateSet::empty().to_string(), "{}");
+    }
+}
@@ -1,10 +1,16 @@
-use super::value::Value;
+use super::{value::Value, CandidateSet};
 
 #[derive(Clone, Copy)]
 pub struct Cell {
-    pub value: Value,
-    pub possible_values: [Value; 9],
-    pub mutable: bool,
+    pub(crate) value: Value,
+    pub(crate) possible_values: CandidateSet,
+    pub(crate) mutable: bool,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Cell {
@@ -12,17 +18,7 @@ impl Cell {
     pub fn new() -> Self {
         Cell {
             value: Value::None,
-            possible_values: [
-                Value::One,
-                Value::Two,
-                Value::Three,
-                Value::Four,
-                Value::Five,
-                Value::Six,
-                Value::Seven,
-                Value::Eight,
-                Value::Nine,
-            ],
+            possible_values: CandidateSet::full(),
             mutable: true,
         }
     }
@@ -34,31 +30,20 @@ impl Cell {
 
     /// Removes the value from the possible values.
     pub fn remove_possible_value(&mut self, val: Value) {
-        if val != Value::None {
-            self.possible_values[val.to_usize() - 1] = Value::None;
-        }
+        self.possible_values.remove(val);
     }
 
     /// Adds the value to the possible values.
     pub fn add_possible_value(&mut self, val: Value) {
-        if val != Value::None {
-            self.possible_values[val.to_usize() - 1] = val;
-        }
+        self.possible_values.insert(val);
     }
 
-    /// Returns a vector of all possible values.
-    pub fn possible_values(&self) -> Vec<Value> {
-        let mut values = Vec::new();
-
+    /// Returns the set of all possible values, empty if the cell has a value.
+    pub fn possible_values(&self) -> CandidateSet {
         if self.value != Value::None {
-            return values;
+            return CandidateSet::empty();
         }
 
-        for val in self.possible_values {
-            if val != Value::None {
-                values.push(val);
-            }
-        }
-        values
+        self.possible_values
     }
 }
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+use super::{House, Value};
+
+/// Two equal givens sharing a house. <br>
+/// `cells` are (row, col) positions in reading order, their bounds are 1..=9.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Conflict {
+    pub value: Value,
+    pub house: House,
+    pub cells: [(usize, usize); 2],
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at {:?} and {:?} in {}",
+            self.value, self.cells[0], self.cells[1], self.house
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use crate::units::box_of;
+
+/// A row, column or 3x3 box of the board. <br>
+/// The index bounds are 1..=9, boxes are numbered left to right, top to bottom.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum House {
+    Row(usize),
+    Column(usize),
+    Box(usize),
+}
+
+impl House {
+    /// Returns all 27 houses, rows first, then columns, then boxes.
+    pub fn all() -> impl Iterator<Item = House> {
+        (1..=9)
+            .map(House::Row)
+            .chain((1..=9).map(House::Column))
+            .chain((1..=9).map(House::Box))
+    }
+
+    /// Returns the box containing the cell. <br>
+    /// `row` and `col` bounds are 1..=9.
+    pub fn box_of(row: usize, col: usize) -> House {
+        House::Box(box_of((row - 1) * 9 + col - 1) + 1)
+    }
+
+    /// Returns the 1 based index of the house.
+    pub fn index(&self) -> usize {
+        match *self {
+            House::Row(i) | House::Column(i) | House::Box(i) => i,
+        }
+    }
+
+    /// Returns the (row, col) positions of the cells in the house in reading order. <br>
+    /// The position bounds are 1..=9.
+    pub fn cells(&self) -> [(usize, usize); 9] {
+        let mut cells = [(0, 0); 9];
+        for (i, cell) in cells.iter_mut().enumerate() {
+            *cell = match *self {
+                House::Row(row) => (row, i + 1),
+                House::Column(col) => (i + 1, col),
+                House::Box(b) => ((b - 1) / 3 * 3 + i / 3 + 1, (b - 1) % 3 * 3 + i % 3 + 1),
+            };
+        }
+        cells
+    }
+}
+
+impl Display for House {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            House::Row(i) => write!(f, "row {i}"),
+            House::Column(i) => write!(f, "column {i}"),
+            House::Box(i) => write!(f, "box {i}"),
+        }
+    }
+}
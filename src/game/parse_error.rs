@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+/// The reason a setup could not be parsed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    /// The setup has more than 9 lines.
+    TooManyRows,
+    /// A line has more than 9 `|` separated columns.
+    TooManyColumns,
+    /// A token is neither empty nor a digit from 1 through 9.
+    InvalidDigit,
+    /// A given can't be placed because it contradicts the givens before it.
+    ContradictoryGiven,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::TooManyRows => write!(f, "the setup must have a maximum of 9 lines"),
+            ParseErrorKind::TooManyColumns => {
+                write!(f, "each line must have a maximum of 9 columns")
+            }
+            ParseErrorKind::InvalidDigit => write!(f, "values must be empty or 1 through 9"),
+            ParseErrorKind::ContradictoryGiven => {
+                write!(f, "the value contradicts the values before it")
+            }
+        }
+    }
+}
+
+/// Error returned by [`SudokuBoard::parse`](super::SudokuBoard::parse). <br>
+/// `line` and `column` are 1 based, `column` counts `|` separated values.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new<S: AsRef<str>>(line: usize, column: usize, token: S, kind: ParseErrorKind) -> Self {
+        ParseError {
+            line,
+            column,
+            token: token.as_ref().to_string(),
+            kind,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Error line {} column {} (`{}`): {}",
+            self.line, self.column, self.token, self.kind
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -1,9 +1,9 @@
-use super::Value;
+use super::CandidateSet;
 
 pub struct PossibleCellValues {
     pub row: usize,
     pub col: usize,
-    pub values: Vec<Value>,
+    pub values: CandidateSet,
 }
 
 impl PartialEq for PossibleCellValues {
@@ -24,4 +24,4 @@ impl Ord for PossibleCellValues {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         other.values.len().cmp(&self.values.len())
     }
-}
\ No newline at end of file
+}
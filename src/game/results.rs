@@ -1,3 +1,5 @@
+use std::{fmt::Display, time::Duration};
+
 use super::value::Value;
 
 #[derive(PartialEq)]
@@ -5,6 +7,8 @@ pub enum AddResult {
     Added(Value),
     NoneValue,
     NotPossible,
+    /// Refused by guard mode, the value disagrees with the solution.
+    Incorrect,
     AlreadySet,
     Solved,
 }
@@ -19,11 +23,75 @@ pub enum ChangeResult {
     Changed(Value, Value),
     NoneValue,
     NotPossible,
+    /// Refused by guard mode, the value disagrees with the solution.
+    Incorrect,
     Immutable,
     Solved,
 }
 
+/// Statistics collected by a [`Solver`](crate::solver::Solver).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct SolveStats {
+    /// Number of search nodes visited.
+    pub nodes: usize,
+    /// Number of tried values that didn't lead to a solution.
+    pub backtracks: usize,
+    /// Deepest level of the search tree reached, the root is level 0.
+    pub max_depth: usize,
+    /// Number of values tried in cells with more than one possible value.
+    pub guesses: usize,
+    /// Number of values placed without guessing, because they were the only possibility.
+    pub propagations: usize,
+    /// Wall time the solver took.
+    pub time: Duration,
+}
+
+impl SolveStats {
+    /// Records a visit of a search node at the given depth.
+    pub fn visit(&mut self, depth: usize) {
+        self.nodes += 1;
+        self.max_depth = self.max_depth.max(depth);
+    }
+}
+
+impl Display for SolveStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "nodes: {}, backtracks: {}, max depth: {}, guesses: {}, propagations: {}, time: {:?}",
+            self.nodes, self.backtracks, self.max_depth, self.guesses, self.propagations, self.time
+        )
+    }
+}
+
 pub enum SolveResult {
-    Solved,
-    Failed,
+    Solved(SolveStats),
+    Failed(SolveStats),
+}
+
+/// Number of solutions found by [`SudokuBoard::count_solutions`](super::SudokuBoard::count_solutions).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SolutionCount {
+    /// The search finished below the limit, this is the exact number of solutions.
+    Exactly(usize),
+    /// The search stopped after finding `limit` solutions.
+    AtLeast(usize),
+}
+
+impl SolutionCount {
+    /// Checks if the board has exactly one solution.
+    pub fn is_unique(&self) -> bool {
+        *self == SolutionCount::Exactly(1)
+    }
+}
+
+/// Result of [`SudokuBoard::check`](super::SudokuBoard::check).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CheckResult {
+    /// The (row, col) positions of all values disagreeing with the solution, empty if all are correct.
+    Mistakes(Vec<(usize, usize)>),
+    /// The givens have no solution.
+    NoSolution,
+    /// The givens have multiple solutions, so values can't be checked.
+    MultipleSolutions,
 }
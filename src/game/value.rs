@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 // TODO: remove Debug
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Value {
     One,
     Two,
@@ -49,6 +49,22 @@ impl Value {
         }
     }
 
+    /// Returns the value for the numbers 1 through 9, `Value::None` otherwise.
+    pub fn from_usize(n: usize) -> Value {
+        match n {
+            1 => Value::One,
+            2 => Value::Two,
+            3 => Value::Three,
+            4 => Value::Four,
+            5 => Value::Five,
+            6 => Value::Six,
+            7 => Value::Seven,
+            8 => Value::Eight,
+            9 => Value::Nine,
+            _ => Value::None,
+        }
+    }
+
     pub fn to_usize(&self) -> usize {
         match self {
             Value::One => 1,
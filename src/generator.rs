@@ -0,0 +1,180 @@
+//! Random puzzle generation with a target difficulty [`Grade`].
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    game::{SolveResult, Value},
+    logic::Grade,
+    SudokuBoard,
+};
+
+/// Number of puzzles [`generate`] tries before giving up.
+pub const MAX_ATTEMPTS: usize = 1000;
+
+/// Generates a random puzzle with a unique solution rated as `grade`, see
+/// [`Generator::try_generate`]. <br>
+/// `None` is returned if none of [`MAX_ATTEMPTS`] puzzles is rated as `grade`.
+pub fn generate(grade: Grade) -> Option<SudokuBoard> {
+    Generator::new().try_generate(grade, MAX_ATTEMPTS)
+}
+
+/// Random puzzle generator, seeded from the system time or a fixed seed for reproducible puzzles.
+pub struct Generator {
+    state: u64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Generator {
+    /// Creates a generator seeded from the system time.
+    pub fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+        Self::with_seed(nanos)
+    }
+
+    /// Creates a generator producing the same puzzles for the same seed.
+    pub fn with_seed(seed: u64) -> Self {
+        // splitmix64 spreads similar seeds apart
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        state ^= state >> 31;
+
+        // xorshift must not start from 0
+        Generator {
+            state: if state == 0 {
+                0x9e37_79b9_7f4a_7c15
+            } else {
+                state
+            },
+        }
+    }
+
+    /// Generates up to `max_attempts` puzzles, returning the first one rated as `grade`. <br>
+    /// Easier grades are found within a few attempts, extreme ones can take many more.
+    pub fn try_generate(&mut self, grade: Grade, max_attempts: usize) -> Option<SudokuBoard> {
+        (0..max_attempts)
+            .map(|_| self.puzzle())
+            .find(|puzzle| puzzle.rate().grade == grade)
+    }
+
+    /// Generates a random puzzle with a unique solution, where no given can be removed without
+    /// losing the uniqueness.
+    pub fn puzzle(&mut self) -> SudokuBoard {
+        let mut values = self.full_grid();
+
+        let mut cells = (0..81).collect::<Vec<_>>();
+        self.__shuffle(&mut cells);
+        for cell in cells {
+            let value = values[cell / 9][cell % 9];
+            values[cell / 9][cell % 9] = Value::None;
+            if !Self::__board(&values).count_solutions(2).is_unique() {
+                values[cell / 9][cell % 9] = value;
+            }
+        }
+
+        Self::__board(&values)
+    }
+
+    /// Returns the values of a random solved board.
+    pub fn full_grid(&mut self) -> [[Value; 9]; 9] {
+        // the boxes on the diagonal don't share houses, so they can be filled independently
+        let mut values = [[Value::None; 9]; 9];
+        for b in 0..3 {
+            let mut digits = (1..=9).map(Value::from_usize).collect::<Vec<_>>();
+            self.__shuffle(&mut digits);
+            for (i, digit) in digits.into_iter().enumerate() {
+                values[b * 3 + i / 3][b * 3 + i % 3] = digit;
+            }
+        }
+
+        let mut board = Self::__board(&values);
+        match board.solve() {
+            SolveResult::Solved(_) => {}
+            SolveResult::Failed(_) => {
+                unreachable!("a board with filled diagonal boxes is solvable")
+            }
+        }
+
+        // relabel the digits, so the rest of the grid isn't always filled the same way
+        let mut digits = (1..=9).map(Value::from_usize).collect::<Vec<_>>();
+        self.__shuffle(&mut digits);
+        for (row, row_values) in values.iter_mut().enumerate() {
+            for (col, value) in row_values.iter_mut().enumerate() {
+                *value = digits[board.value(row + 1, col + 1).to_usize() - 1];
+            }
+        }
+        values
+    }
+
+    /// Creates a board with the values as givens.
+    fn __board(values: &[[Value; 9]; 9]) -> SudokuBoard {
+        let input = values
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&value| match value {
+                        Value::None => String::new(),
+                        value => value.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("|")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        SudokuBoard::parse(&input).expect("the values of a generated board don't contradict")
+    }
+
+    fn __shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.__next() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Returns the next xorshift64 random number.
+    fn __next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_seed_produces_random_numbers() {
+        for seed in [0, 0x9e37_79b9_7f4a_7c15, u64::MAX] {
+            let mut generator = Generator::with_seed(seed);
+            let numbers = (0..4).map(|_| generator.__next()).collect::<Vec<_>>();
+            assert!(numbers.iter().all(|&n| n != 0), "{seed}: {numbers:?}");
+        }
+    }
+
+    #[test]
+    fn same_seed_same_puzzle() {
+        let puzzle = Generator::with_seed(42).puzzle();
+        assert_eq!(
+            puzzle.to_string(),
+            Generator::with_seed(42).puzzle().to_string()
+        );
+        assert!(puzzle.count_solutions(2).is_unique());
+    }
+
+    #[test]
+    fn try_generate_gives_up() {
+        let mut generator = Generator::with_seed(42);
+        assert!(generator.try_generate(Grade::Easy, 0).is_none());
+        let puzzle = generator.try_generate(Grade::Easy, 100).unwrap();
+        assert_eq!(puzzle.rate().grade, Grade::Easy);
+    }
+}
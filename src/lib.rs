@@ -0,0 +1,12 @@
+//! Sudoku board and solver library. <br>
+//! The `sudoku_solver` binary is a thin REPL on top of this crate.
+
+pub mod game;
+pub mod generator;
+pub mod logic;
+pub mod solver;
+#[cfg(test)]
+mod testing;
+mod units;
+
+pub use game::SudokuBoard;
@@ -0,0 +1,214 @@
+//! Human style logical solver, applying techniques in order of difficulty and never guessing.
+
+use std::time::Instant;
+
+use crate::{
+    game::{AddResult, SolveResult, SolveStats, SudokuBoard},
+    solver::Solver,
+};
+
+mod als;
+mod chains;
+use chains::ChainKind;
+mod fish;
+mod forcing;
+use fish::Fins;
+mod grid;
+use grid::Grid;
+mod intersections;
+mod link;
+pub use link::{Link, Node};
+mod playback;
+pub use playback::Playback;
+mod rating;
+pub use rating::{Grade, Rating};
+mod single_digit;
+mod singles;
+mod step;
+mod subsets;
+pub use step::Step;
+mod technique;
+pub use technique::Technique;
+mod uniqueness;
+mod wings;
+
+/// Logical solver finding one [`Step`] at a time with the easiest applicable technique.
+pub struct LogicalSolver {
+    techniques: Vec<Technique>,
+    max_chain_length: usize,
+}
+
+impl Default for LogicalSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogicalSolver {
+    /// Creates a solver using all techniques.
+    pub fn new() -> Self {
+        Self::with_techniques(Technique::all())
+    }
+
+    /// Creates a solver using only the given techniques, they are tried easiest first.
+    pub fn with_techniques(techniques: &[Technique]) -> Self {
+        let mut techniques = techniques.to_vec();
+        techniques.sort();
+        LogicalSolver {
+            techniques,
+            max_chain_length: 20,
+        }
+    }
+
+    /// Limits chains to `length` links, forcing chains follow each assumption at most that far.
+    pub fn max_chain_length(mut self, length: usize) -> Self {
+        self.max_chain_length = length;
+        self
+    }
+
+    /// Returns the next deduction without applying it. <br>
+    /// `None` is returned if the board is solved, broken or no technique applies. <br>
+    /// Techniques [requiring uniqueness](Technique::requires_uniqueness) are skipped unless the
+    /// board has a unique solution, which is only checked once they are reached.
+    pub fn next_step(&self, board: &SudokuBoard) -> Option<Step> {
+        let grid = Grid::new(board);
+        if grid.is_broken() {
+            return None;
+        }
+
+        let mut unique = None;
+        self.techniques
+            .iter()
+            .filter(|technique| {
+                !technique.requires_uniqueness()
+                    || *unique.get_or_insert_with(|| board.count_solutions(2).is_unique())
+            })
+            .find_map(|technique| self.__find(*technique, &grid))
+    }
+
+    /// Applies deductions to the board until it is solved or no technique applies. <br>
+    /// Returns the applied steps in order.
+    pub fn run(&self, board: &mut SudokuBoard) -> Vec<Step> {
+        let mut steps = Vec::new();
+        while let Some(step) = self.next_step(board) {
+            if !Self::apply(board, &step) {
+                break;
+            }
+            steps.push(step);
+        }
+        steps
+    }
+
+    /// Applies the placement and eliminations of a step to the board. <br>
+    /// Returns false if the placement was refused by the board.
+    pub fn apply(board: &mut SudokuBoard, step: &Step) -> bool {
+        if let Some((row, col, value)) = step.placement {
+            if !matches!(
+                board.add(row, col, value),
+                AddResult::Added(_) | AddResult::Solved
+            ) {
+                return false;
+            }
+        }
+
+        for &(row, col, value) in &step.eliminations {
+            board.eliminate(row, col, value);
+        }
+        true
+    }
+
+    fn __find(&self, technique: Technique, grid: &Grid) -> Option<Step> {
+        let length = self.max_chain_length;
+        match technique {
+            Technique::HiddenSingle => singles::hidden_single(grid),
+            Technique::NakedSingle => singles::naked_single(grid),
+            Technique::Pointing => intersections::pointing(grid),
+            Technique::Claiming => intersections::claiming(grid),
+            Technique::NakedPair => subsets::naked_subset(grid, 2, technique),
+            Technique::HiddenPair => subsets::hidden_subset(grid, 2, technique),
+            Technique::NakedTriple => subsets::naked_subset(grid, 3, technique),
+            Technique::HiddenTriple => subsets::hidden_subset(grid, 3, technique),
+            Technique::NakedQuad => subsets::naked_subset(grid, 4, technique),
+            Technique::HiddenQuad => subsets::hidden_subset(grid, 4, technique),
+            Technique::XWing => fish::fish(grid, 2, Fins::None, technique),
+            Technique::FinnedXWing => fish::fish(grid, 2, Fins::Finned, technique),
+            Technique::SashimiXWing => fish::fish(grid, 2, Fins::Sashimi, technique),
+            Technique::Swordfish => fish::fish(grid, 3, Fins::None, technique),
+            Technique::FinnedSwordfish => fish::fish(grid, 3, Fins::Finned, technique),
+            Technique::SashimiSwordfish => fish::fish(grid, 3, Fins::Sashimi, technique),
+            Technique::Jellyfish => fish::fish(grid, 4, Fins::None, technique),
+            Technique::FinnedJellyfish => fish::fish(grid, 4, Fins::Finned, technique),
+            Technique::SashimiJellyfish => fish::fish(grid, 4, Fins::Sashimi, technique),
+            Technique::XYWing => wings::xy_wing(grid),
+            Technique::XYZWing => wings::xyz_wing(grid),
+            Technique::WWing => wings::w_wing(grid),
+            Technique::Skyscraper => single_digit::skyscraper(grid),
+            Technique::TwoStringKite => single_digit::two_string_kite(grid),
+            Technique::EmptyRectangle => single_digit::empty_rectangle(grid),
+            Technique::SimpleColoring => single_digit::simple_coloring(grid),
+            Technique::UniqueRectangleType1 => uniqueness::unique_rectangle_type_1(grid),
+            Technique::UniqueRectangleType2 => uniqueness::unique_rectangle_type_2(grid),
+            Technique::UniqueRectangleType3 => uniqueness::unique_rectangle_type_3(grid),
+            Technique::UniqueRectangleType4 => uniqueness::unique_rectangle_type_4(grid),
+            Technique::BugPlusOne => uniqueness::bug_plus_one(grid),
+            Technique::XChain => chains::chain(grid, ChainKind::X, length, technique),
+            Technique::XYChain => chains::chain(grid, ChainKind::XY, length, technique),
+            Technique::Aic => chains::chain(grid, ChainKind::Aic, length, technique),
+            Technique::AlsXz => als::als_xz(grid),
+            Technique::AlsXyWing => als::als_xy_wing(grid),
+            Technique::DeathBlossom => als::death_blossom(grid),
+            Technique::CellForcingChain => forcing::cell_forcing_chain(grid, length),
+            Technique::UnitForcingChain => forcing::unit_forcing_chain(grid, length),
+        }
+    }
+}
+
+impl Solver for LogicalSolver {
+    fn name(&self) -> &'static str {
+        "logic"
+    }
+
+    fn solve(&self, board: &mut SudokuBoard) -> SolveResult {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+
+        // work on a copy, so the board is left in its original state when stuck
+        let mut copy = board.clone();
+        stats.propagations = self.run(&mut copy).len();
+        stats.visit(0);
+        stats.time = start.elapsed();
+
+        if copy.is_solved() {
+            *board = copy;
+            SolveResult::Solved(stats)
+        } else {
+            SolveResult::Failed(stats)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{check_steps, UNIQUE};
+
+    #[test]
+    fn singles_agree_with_the_solution() {
+        let solver =
+            LogicalSolver::with_techniques(&[Technique::NakedSingle, Technique::HiddenSingle]);
+        check_steps(&solver, UNIQUE);
+    }
+
+    #[test]
+    fn steps_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for puzzle in [
+            UNIQUE,
+            "..62..1.52...7...8....1.9..8...5.41...58..6..6..42...................7.93829.....",
+            "3..7.9..........4.7.61..5.8.6.......1.7..8...2.89....4....6...3...517...6.1..42..",
+            ".1...72..7.8..4..6....3....8.5.....9..6....2......67...62.9...1..75........41..8.",
+        ] {
+            check_steps(&solver, puzzle);
+        }
+    }
+}
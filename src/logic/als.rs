@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use crate::{
+    game::{CandidateSet, Value},
+    units::{sees, HOUSES},
+};
+
+use super::{
+    grid::{house, pos, Grid},
+    Link, Node, Step, Technique,
+};
+
+/// `n` empty cells of a house with `n + 1` possible values between them (almost locked set),
+/// cells are indexed `row * 9 + col`.
+struct Als {
+    cells: Vec<usize>,
+    values: CandidateSet,
+    house: usize,
+}
+
+impl Als {
+    /// Returns the cells having the value as a possible value.
+    fn cells_with(&self, grid: &Grid, value: Value) -> Vec<usize> {
+        self.cells
+            .iter()
+            .copied()
+            .filter(|&cell| grid.candidates[cell].contains(value))
+            .collect()
+    }
+
+    fn overlaps(&self, other: &Als) -> bool {
+        self.cells.iter().any(|cell| other.cells.contains(cell))
+    }
+
+    /// Returns the values that can't be in both sets, as all their cells see each other.
+    fn restricted_common(&self, grid: &Grid, other: &Als) -> CandidateSet {
+        (self.values & other.values)
+            .into_iter()
+            .filter(|&value| {
+                let others = other.cells_with(grid, value);
+                self.cells_with(grid, value)
+                    .iter()
+                    .all(|&a| others.iter().all(|&b| a != b && sees(a, b)))
+            })
+            .collect()
+    }
+
+    fn node(&self, grid: &Grid, value: Value) -> Node {
+        let cells = self.cells_with(grid, value).into_iter().map(pos).collect();
+        Node::group(cells, value)
+    }
+}
+
+/// Returns all almost locked sets, each set of cells once.
+fn almost_locked_sets(grid: &Grid) -> Vec<Als> {
+    let mut sets = Vec::new();
+    let mut seen = HashSet::new();
+    for (h, cells) in HOUSES.iter().enumerate() {
+        let empty = cells
+            .iter()
+            .copied()
+            .filter(|&cell| grid.values[cell] == Value::None)
+            .collect::<Vec<_>>();
+
+        for mask in 1..1u32 << empty.len() {
+            let cells = (0..empty.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| empty[i])
+                .collect::<Vec<_>>();
+            let values = cells.iter().fold(CandidateSet::empty(), |values, &cell| {
+                values | grid.candidates[cell]
+            });
+            if values.len() == cells.len() + 1 && seen.insert(cells.clone()) {
+                sets.push(Als {
+                    cells,
+                    values,
+                    house: h,
+                });
+            }
+        }
+    }
+    sets
+}
+
+/// Returns the removals of a value from the cells outside the sets seeing all its cells in them.
+fn eliminations(grid: &Grid, sets: &[&Als], value: Value) -> Vec<(usize, usize, Value)> {
+    let cells = sets
+        .iter()
+        .flat_map(|set| set.cells_with(grid, value))
+        .collect::<Vec<_>>();
+    (0..81)
+        .filter(|&cell| grid.candidates[cell].contains(value))
+        .filter(|cell| !sets.iter().any(|set| set.cells.contains(cell)))
+        .filter(|&cell| cells.iter().all(|&other| sees(cell, other)))
+        .map(|cell| {
+            let (row, col) = pos(cell);
+            (row, col, value)
+        })
+        .collect()
+}
+
+fn step(technique: Technique, sets: &[&Als], digits: CandidateSet) -> Step {
+    let mut step = Step::new(technique, digits);
+    for set in sets {
+        step.cells.extend(set.cells.iter().map(|&cell| pos(cell)));
+        if !step.houses.contains(&house(set.house)) {
+            step.houses.push(house(set.house));
+        }
+        step.sets
+            .push(set.cells.iter().map(|&cell| pos(cell)).collect());
+    }
+    step
+}
+
+/// Finds two sets with a restricted common value x, one of them holds another common value z
+/// which is removed from the cells seeing all its cells in both sets.
+pub(crate) fn als_xz(grid: &Grid) -> Option<Step> {
+    let sets = almost_locked_sets(grid);
+    sets.iter().enumerate().find_map(|(i, a)| {
+        sets[i + 1..]
+            .iter()
+            .filter(|b| !a.overlaps(b))
+            .find_map(|b| {
+                let restricted = a.restricted_common(grid, b);
+                restricted.into_iter().find_map(|x| {
+                    ((a.values & b.values) - CandidateSet::single(x))
+                        .into_iter()
+                        .find_map(|z| {
+                            let eliminations = eliminations(grid, &[a, b], z);
+                            if eliminations.is_empty() {
+                                return None;
+                            }
+
+                            let digits = CandidateSet::single(x) | CandidateSet::single(z);
+                            let mut step = step(Technique::AlsXz, &[a, b], digits);
+                            step.links = vec![Link::weak(a.node(grid, x), b.node(grid, x))];
+                            step.eliminations = eliminations;
+                            Some(step)
+                        })
+                })
+            })
+    })
+}
+
+/// Finds two sets each sharing a different restricted common value with a third set, a value z
+/// common to both is removed from the cells seeing all its cells in them.
+pub(crate) fn als_xy_wing(grid: &Grid) -> Option<Step> {
+    let sets = almost_locked_sets(grid);
+    sets.iter().find_map(|pivot| {
+        let wings = sets
+            .iter()
+            .filter(|set| !set.overlaps(pivot))
+            .map(|set| (set, set.restricted_common(grid, pivot)))
+            .filter(|(_, restricted)| !restricted.is_empty())
+            .collect::<Vec<_>>();
+
+        wings
+            .iter()
+            .enumerate()
+            .find_map(|(i, &(a, a_restricted))| {
+                wings[i + 1..]
+                    .iter()
+                    .filter(|(b, _)| !a.overlaps(b))
+                    .find_map(|&(b, b_restricted)| {
+                        a_restricted.into_iter().find_map(|x| {
+                            (b_restricted - CandidateSet::single(x))
+                                .into_iter()
+                                .find_map(|y| {
+                                    let used = CandidateSet::single(x) | CandidateSet::single(y);
+                                    ((a.values & b.values) - used).into_iter().find_map(|z| {
+                                        let eliminations = eliminations(grid, &[a, b], z);
+                                        if eliminations.is_empty() {
+                                            return None;
+                                        }
+
+                                        let digits = used | CandidateSet::single(z);
+                                        let mut step =
+                                            step(Technique::AlsXyWing, &[pivot, a, b], digits);
+                                        step.links = vec![
+                                            Link::weak(a.node(grid, x), pivot.node(grid, x)),
+                                            Link::weak(pivot.node(grid, y), b.node(grid, y)),
+                                        ];
+                                        step.eliminations = eliminations;
+                                        Some(step)
+                                    })
+                                })
+                        })
+                    })
+            })
+    })
+}
+
+/// Finds a stem cell where each possible value sees all its cells in a different set (petal),
+/// a value common to all petals is removed from the cells seeing all its cells in them.
+pub(crate) fn death_blossom(grid: &Grid) -> Option<Step> {
+    let sets = almost_locked_sets(grid);
+    (0..81)
+        .filter(|&stem| grid.values[stem] == Value::None)
+        .filter(|&stem| (2..=3).contains(&grid.candidates[stem].len()))
+        .find_map(|stem| {
+            let stem_values = grid.candidates[stem].into_iter().collect::<Vec<_>>();
+            let petals = stem_values
+                .iter()
+                .map(|&value| {
+                    sets.iter()
+                        .filter(|set| !set.cells.contains(&stem) && set.values.contains(value))
+                        .filter(|set| {
+                            set.cells_with(grid, value)
+                                .iter()
+                                .all(|&cell| sees(cell, stem))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            __blossom(grid, stem, &stem_values, &petals, &mut Vec::new())
+        })
+}
+
+/// Chooses a petal for each stem value in turn, checking the eliminations once all are chosen.
+fn __blossom<'a>(
+    grid: &Grid,
+    stem: usize,
+    stem_values: &[Value],
+    petals: &[Vec<&'a Als>],
+    chosen: &mut Vec<&'a Als>,
+) -> Option<Step> {
+    let Some(options) = petals.get(chosen.len()) else {
+        let common = chosen
+            .iter()
+            .fold(CandidateSet::full(), |common, petal| common & petal.values)
+            - stem_values.iter().copied().collect();
+        return common.into_iter().find_map(|z| {
+            let eliminations = eliminations(grid, chosen, z);
+            if eliminations.is_empty() {
+                return None;
+            }
+
+            let digits = grid.candidates[stem] | CandidateSet::single(z);
+            let mut step = step(Technique::DeathBlossom, chosen, digits);
+            step.cells.insert(0, pos(stem));
+            step.pivot = Some(pos(stem));
+            step.links = stem_values
+                .iter()
+                .zip(chosen.iter())
+                .map(|(&value, petal)| {
+                    Link::weak(Node::cell(pos(stem), value), petal.node(grid, value))
+                })
+                .collect();
+            step.eliminations = eliminations;
+            Some(step)
+        });
+    };
+
+    options.iter().find_map(|&petal| {
+        if chosen.iter().any(|other| other.overlaps(petal)) {
+            return None;
+        }
+        // some value other than the stem values must stay common to all petals
+        let common = chosen
+            .iter()
+            .fold(petal.values, |common, other| common & other.values);
+        if (common - stem_values.iter().copied().collect()).is_empty() {
+            return None;
+        }
+
+        chosen.push(petal);
+        let step = __blossom(grid, stem, stem_values, petals, chosen);
+        chosen.pop();
+        step
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Technique},
+        testing::check_steps,
+    };
+
+    #[test]
+    fn almost_locked_sets_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for (puzzle, technique) in [
+            (
+                "....81.2...73......25..4.....2.4.3.99.3...78.6........5......9.....2...5...91..3.",
+                Technique::AlsXz,
+            ),
+            (
+                ".5.....8.62......4..74..9...79......1......2.....5...3...5..63.2..6...5...5..7.4.",
+                Technique::AlsXyWing,
+            ),
+            (
+                "..9...8....3..5......61.........3.4..4..6..73...7..1........3.89.8.5..6416...4.9.",
+                Technique::DeathBlossom,
+            ),
+        ] {
+            assert!(
+                check_steps(&solver, puzzle).contains(&technique),
+                "no {technique} in {puzzle}"
+            );
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::VecDeque;
+
+use crate::{
+    game::{CandidateSet, Value},
+    units::{sees, HOUSES},
+};
+
+use super::{
+    grid::{pos, Grid},
+    Link, Node, Step, Technique,
+};
+
+/// The links a chain may use.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChainKind {
+    /// Links of a single value between cells.
+    X,
+    /// Strong links inside cells with 2 possible values, weak links between cells.
+    XY,
+    /// All links, including grouped nodes.
+    Aic,
+}
+
+/// A value in one or more cells of a house, cells are indexed `row * 9 + col`.
+struct ChainNode {
+    cells: Vec<usize>,
+    value: Value,
+}
+
+impl ChainNode {
+    fn node(&self) -> Node {
+        let cells = self.cells.iter().map(|&cell| pos(cell)).collect();
+        Node::group(cells, self.value)
+    }
+
+    /// Checks if a cell sees all cells of the node.
+    fn seen_by(&self, cell: usize) -> bool {
+        !self.cells.contains(&cell) && self.cells.iter().all(|&other| sees(cell, other))
+    }
+}
+
+/// The nodes of a grid and the links between them.
+struct Graph {
+    nodes: Vec<ChainNode>,
+    strong: Vec<Vec<usize>>,
+    weak: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn new(grid: &Grid, kind: ChainKind) -> Self {
+        let mut nodes = Vec::new();
+        for cell in 0..81 {
+            if grid.values[cell] == Value::None {
+                for value in grid.candidates[cell] {
+                    nodes.push(ChainNode {
+                        cells: vec![cell],
+                        value,
+                    });
+                }
+            }
+        }
+        if kind == ChainKind::Aic {
+            // the possible cells of a value in the intersection of a box and a line
+            for b in &HOUSES[18..27] {
+                for line in &HOUSES[0..18] {
+                    let cells = b
+                        .iter()
+                        .copied()
+                        .filter(|cell| line.contains(cell))
+                        .collect::<Vec<_>>();
+                    if cells.len() != 3 {
+                        continue;
+                    }
+                    for value in (1..=9).map(Value::from_usize) {
+                        let group = cells
+                            .iter()
+                            .copied()
+                            .filter(|&cell| grid.candidates[cell].contains(value))
+                            .collect::<Vec<_>>();
+                        if group.len() >= 2 {
+                            nodes.push(ChainNode {
+                                cells: group,
+                                value,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut graph = Graph {
+            strong: vec![Vec::new(); nodes.len()],
+            weak: vec![Vec::new(); nodes.len()],
+            nodes,
+        };
+        graph.__link(grid, kind);
+        graph
+    }
+
+    fn __link(&mut self, grid: &Grid, kind: ChainKind) {
+        let count = self.nodes.len();
+
+        // the two values of a cell with 2 possible values
+        if kind != ChainKind::X {
+            for a in 0..count {
+                for b in a + 1..count {
+                    let (first, second) = (&self.nodes[a], &self.nodes[b]);
+                    if first.cells.len() == 1 && first.cells == second.cells {
+                        if grid.candidates[first.cells[0]].len() == 2 {
+                            self.__add(a, b, true);
+                        }
+                        if kind == ChainKind::Aic {
+                            self.__add(a, b, false);
+                        }
+                    }
+                }
+            }
+        }
+
+        for value in (1..=9).map(Value::from_usize) {
+            let nodes = (0..count)
+                .filter(|&node| self.nodes[node].value == value)
+                .collect::<Vec<_>>();
+
+            // two nodes being all possible cells of the value in a house
+            if kind != ChainKind::XY {
+                for h in 0..27 {
+                    let cells = grid.cells_with(h, value);
+                    let inside = nodes
+                        .iter()
+                        .copied()
+                        .filter(|&node| self.nodes[node].cells.iter().all(|c| cells.contains(c)))
+                        .collect::<Vec<_>>();
+                    for (i, &a) in inside.iter().enumerate() {
+                        for &b in &inside[i + 1..] {
+                            let (first, second) = (&self.nodes[a], &self.nodes[b]);
+                            if first.cells.len() + second.cells.len() == cells.len()
+                                && first.cells.iter().all(|cell| !second.cells.contains(cell))
+                            {
+                                self.__add(a, b, true);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // two nodes seeing each other
+            for (i, &a) in nodes.iter().enumerate() {
+                for &b in &nodes[i + 1..] {
+                    let (first, second) = (&self.nodes[a], &self.nodes[b]);
+                    if first.cells.iter().all(|&cell| second.seen_by(cell)) {
+                        self.__add(a, b, false);
+                    }
+                }
+            }
+        }
+    }
+
+    fn __add(&mut self, a: usize, b: usize, strong: bool) {
+        let links = if strong {
+            &mut self.strong
+        } else {
+            &mut self.weak
+        };
+        if !links[a].contains(&b) {
+            links[a].push(b);
+            links[b].push(a);
+        }
+    }
+
+    /// Returns the removals implied by one of the two nodes being true.
+    fn eliminations(&self, grid: &Grid, start: usize, end: usize) -> Vec<(usize, usize, Value)> {
+        let (start, end) = (&self.nodes[start], &self.nodes[end]);
+        let mut eliminations = Vec::new();
+        if start.value == end.value {
+            eliminations.extend(
+                (0..81)
+                    .filter(|&cell| grid.candidates[cell].contains(start.value))
+                    .filter(|&cell| start.seen_by(cell) && end.seen_by(cell))
+                    .map(|cell| (cell, start.value)),
+            );
+        } else if start.cells.len() == 1 && start.cells == end.cells {
+            // one of the two values is in the cell
+            let cell = start.cells[0];
+            let kept = CandidateSet::single(start.value) | CandidateSet::single(end.value);
+            eliminations.extend(
+                (grid.candidates[cell] - kept)
+                    .into_iter()
+                    .map(|v| (cell, v)),
+            );
+        } else {
+            // a cell of one node seeing the other node can't have the value of the other node
+            for (node, other) in [(start, end), (end, start)] {
+                if let [cell] = node.cells[..] {
+                    if grid.candidates[cell].contains(other.value) && other.seen_by(cell) {
+                        eliminations.push((cell, other.value));
+                    }
+                }
+            }
+        }
+
+        eliminations
+            .into_iter()
+            .map(|(cell, value)| {
+                let (row, col) = pos(cell);
+                (row, col, value)
+            })
+            .collect()
+    }
+}
+
+/// Finds a chain of alternating strong and weak links starting and ending with a strong link,
+/// so one of its end nodes is true. Values are removed from cells that can see both ends. <br>
+/// Chains are searched shortest first with at most `max_length` links, the shortest chain of
+/// all start nodes is returned as longer chains rate harder.
+pub(crate) fn chain(
+    grid: &Grid,
+    kind: ChainKind,
+    max_length: usize,
+    technique: Technique,
+) -> Option<Step> {
+    let graph = Graph::new(grid, kind);
+    let count = graph.nodes.len();
+
+    (0..count)
+        .filter_map(|start| {
+            // breadth first search over (node, reached by a strong link) states
+            let state = |node: usize, strong: bool| node * 2 + strong as usize;
+            let mut parent = vec![None; count * 2];
+            let mut depth = vec![0; count * 2];
+            let mut queue = VecDeque::from([state(start, false)]);
+            parent[state(start, false)] = Some(usize::MAX);
+
+            while let Some(current) = queue.pop_front() {
+                let (node, strong) = (current / 2, current % 2 == 1);
+                if strong && depth[current] >= 3 && kind_matches(&graph, kind, start, node) {
+                    let eliminations = graph.eliminations(grid, start, node);
+                    if !eliminations.is_empty() {
+                        let mut step = Step::new(technique, CandidateSet::empty());
+                        step.links = __links(&graph, &parent, current);
+                        for link in &step.links {
+                            step.digits.insert(link.from.value);
+                            for &cell in link.from.cells.iter().chain(&link.to.cells) {
+                                if !step.cells.contains(&cell) {
+                                    step.cells.push(cell);
+                                }
+                            }
+                        }
+                        step.eliminations = eliminations;
+                        return Some(step);
+                    }
+                }
+                if depth[current] == max_length {
+                    continue;
+                }
+
+                // a chain continues with a strong link after a weak one and vice versa
+                let next = if strong {
+                    &graph.weak[node]
+                } else {
+                    &graph.strong[node]
+                };
+                for &other in next {
+                    let next_state = state(other, !strong);
+                    if parent[next_state].is_none() {
+                        parent[next_state] = Some(current);
+                        depth[next_state] = depth[current] + 1;
+                        queue.push_back(next_state);
+                    }
+                }
+            }
+            None
+        })
+        .min_by_key(|step| step.links.len())
+}
+
+/// Checks if the ends of a chain fit the kind, X- and XY-chains end with the start value.
+fn kind_matches(graph: &Graph, kind: ChainKind, start: usize, end: usize) -> bool {
+    kind == ChainKind::Aic || graph.nodes[start].value == graph.nodes[end].value
+}
+
+/// Follows the parents of a search state back to the start, returning the links in order.
+fn __links(graph: &Graph, parent: &[Option<usize>], end: usize) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut current = end;
+    while let Some(previous) = parent[current].filter(|&p| p != usize::MAX) {
+        let (from, to) = (
+            graph.nodes[previous / 2].node(),
+            graph.nodes[current / 2].node(),
+        );
+        links.push(if current % 2 == 1 {
+            Link::strong(from, to)
+        } else {
+            Link::weak(from, to)
+        });
+        current = previous;
+    }
+    links.reverse();
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Technique},
+        testing::{check_steps, find_step},
+    };
+
+    const GROUPED: &str =
+        "9...6......8...63...281..4.....2.3....7....8.3.....2194....6...5....48...965.....";
+
+    #[test]
+    fn chains_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for (puzzle, technique) in [
+            (
+                "...2.8..4..9....31...3..7.6..2.1.6..5....6..763.5....9.7.......4.3.......65...9.3",
+                Technique::XChain,
+            ),
+            (
+                "........2.6..7..8.7..1......9.......38.46.2.5...32..6.5...3..98.2...75.4.....8...",
+                Technique::XYChain,
+            ),
+            (GROUPED, Technique::Aic),
+        ] {
+            assert!(
+                check_steps(&solver, puzzle).contains(&technique),
+                "no {technique} in {puzzle}"
+            );
+        }
+    }
+
+    #[test]
+    fn aic_links_grouped_nodes() {
+        let (_, step) = find_step(&LogicalSolver::new(), GROUPED, |step| {
+            step.technique == Technique::Aic
+        });
+        assert!(
+            step.links
+                .iter()
+                .any(|link| link.from.cells.len() > 1 || link.to.cells.len() > 1),
+            "{step}"
+        );
+        // the links alternate between strong and weak, starting and ending strong
+        assert!(step.links.len() % 2 == 1, "{step}");
+        for (i, link) in step.links.iter().enumerate() {
+            assert_eq!(link.strong, i % 2 == 0, "{step}");
+        }
+        for pair in step.links.windows(2) {
+            assert_eq!(pair[0].to, pair[1].from, "{step}");
+        }
+    }
+
+    #[test]
+    fn max_chain_length_stops_longer_chains() {
+        let puzzle =
+            "5..38..........8....76.9....7.1...8...28..41.4...67.2...4...7.9.29....6........3.";
+        let (board, step) = find_step(&LogicalSolver::new(), puzzle, |step| {
+            step.technique == Technique::XYChain && step.links.len() > 8
+        });
+        let length = step.links.len();
+
+        let capped = LogicalSolver::new().max_chain_length(length - 1);
+        let other = capped.next_step(&board).unwrap();
+        assert_ne!(other, step);
+        assert!(other.links.len() < length, "{other}");
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    game::{CandidateSet, Value},
+    units::box_of,
+};
+
+use super::{
+    grid::{combinations, house, pos, Grid},
+    Step, Technique,
+};
+
+/// The fin requirement of a fish.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Fins {
+    /// No fins, the base lines are covered exactly.
+    None,
+    /// Fins in one box, each base line has at least 2 cells in the cover lines.
+    Finned,
+    /// Fins in one box and a base line with a single cell in the cover lines.
+    Sashimi,
+}
+
+/// Finds a fish of `size` base rows (or columns) whose possible cells for a value lie in `size`
+/// cover columns (or rows), apart from the `fins`. The value is removed from the other cells of
+/// the cover lines, for finned fish only from those sharing the box of the fins.
+pub(crate) fn fish(grid: &Grid, size: usize, fins: Fins, technique: Technique) -> Option<Step> {
+    (1..=9).map(Value::from_usize).find_map(|value| {
+        [false, true]
+            .into_iter()
+            .find_map(|by_col| fish_for(grid, value, by_col, size, fins, technique))
+    })
+}
+
+/// Searches fish for a value with rows as base lines, or columns if `by_col`.
+fn fish_for(
+    grid: &Grid,
+    value: Value,
+    by_col: bool,
+    size: usize,
+    fins: Fins,
+    technique: Technique,
+) -> Option<Step> {
+    let cell = |line: usize, index: usize| {
+        if by_col {
+            index * 9 + line
+        } else {
+            line * 9 + index
+        }
+    };
+
+    // bitmask of the cover indices of the possible cells of each base line
+    let mut positions = [0u16; 9];
+    for (line, mask) in positions.iter_mut().enumerate() {
+        for index in 0..9 {
+            if grid.candidates[cell(line, index)].contains(value) {
+                *mask |= 1 << index;
+            }
+        }
+    }
+
+    let lines = (0..9)
+        .filter(|&line| match fins {
+            Fins::None => (2..=size as u32).contains(&positions[line].count_ones()),
+            _ => positions[line] != 0,
+        })
+        .collect::<Vec<_>>();
+
+    combinations(&lines, size).into_iter().find_map(|base| {
+        let union = base.iter().fold(0, |union, &line| union | positions[line]);
+        let cover_sets = match fins {
+            Fins::None if union.count_ones() as usize == size => vec![union],
+            Fins::None => return None,
+            _ if union.count_ones() as usize <= size => return None,
+            _ => {
+                let indices = (0..9)
+                    .filter(|i| union & (1 << i) != 0)
+                    .collect::<Vec<u16>>();
+                combinations(&indices, size)
+                    .into_iter()
+                    .map(|covers| covers.iter().fold(0, |mask, i| mask | (1 << i)))
+                    .collect()
+            }
+        };
+
+        cover_sets.into_iter().find_map(|covers: u16| {
+            let fin_cells = base
+                .iter()
+                .flat_map(|&line| {
+                    (0..9)
+                        .filter(move |index| positions[line] & !covers & (1 << index) != 0)
+                        .map(move |index| cell(line, index))
+                })
+                .collect::<Vec<_>>();
+            let fin_box = fin_cells.first().map(|&fin| box_of(fin));
+            if fin_cells.iter().any(|&fin| Some(box_of(fin)) != fin_box) {
+                return None;
+            }
+
+            let cover_counts = base
+                .iter()
+                .map(|&line| (positions[line] & covers).count_ones())
+                .collect::<Vec<_>>();
+            if cover_counts.contains(&0) {
+                return None;
+            }
+            let sashimi = cover_counts.contains(&1);
+            match fins {
+                Fins::Finned if sashimi => return None,
+                Fins::Sashimi if !sashimi => return None,
+                _ => {}
+            }
+
+            let eliminations = (0..9)
+                .filter(|index| covers & (1 << index) != 0)
+                .flat_map(|index| (0..9).map(move |line| (line, index)))
+                .filter(|(line, _)| !base.contains(line))
+                .map(|(line, index)| cell(line, index))
+                .filter(|&target| {
+                    grid.candidates[target].contains(value)
+                        && fin_box.is_none_or(|fin_box| box_of(target) == fin_box)
+                })
+                .map(|target| {
+                    let (row, col) = pos(target);
+                    (row, col, value)
+                })
+                .collect::<Vec<_>>();
+            if eliminations.is_empty() {
+                return None;
+            }
+
+            let mut step = Step::new(technique, CandidateSet::single(value));
+            let mut cells = base
+                .iter()
+                .flat_map(|&line| {
+                    (0..9)
+                        .filter(move |index| positions[line] & (1 << index) != 0)
+                        .map(move |index| cell(line, index))
+                })
+                .collect::<Vec<_>>();
+            cells.sort();
+            step.cells = cells.into_iter().map(pos).collect();
+            let (base_offset, cover_offset) = if by_col { (9, 0) } else { (0, 9) };
+            step.houses = base
+                .iter()
+                .map(|&line| house(base_offset + line))
+                .chain(
+                    (0..9)
+                        .filter(|index| covers & (1 << index) != 0)
+                        .map(|index| house(cover_offset + index)),
+                )
+                .collect();
+            step.fins = fin_cells.into_iter().map(pos).collect();
+            step.eliminations = eliminations;
+            Some(step)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Technique},
+        testing::{check_steps, solver_without},
+    };
+
+    #[test]
+    fn fish_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for (puzzle, techniques) in [
+            (
+                "61.3....8.........9..1.83..3.94..52....9.5....8......1.......32.51..6.9.8....2..6",
+                &[Technique::Swordfish][..],
+            ),
+            (
+                "..2...9..715.....43.....52...8.72......61....6.7..92.3.....14.....9.53......8..5.",
+                &[Technique::FinnedXWing, Technique::FinnedSwordfish],
+            ),
+            (
+                "..7.1236......8.1..6.7..8...5..6......9..14.3....3....89......1..1...5....3....49",
+                &[Technique::SashimiXWing],
+            ),
+            (
+                "3...1........2....4.29.....7..38...913...95.76..1...8......619...67....3.5....8..",
+                &[Technique::SashimiSwordfish, Technique::FinnedJellyfish],
+            ),
+        ] {
+            let used = check_steps(&solver, puzzle);
+            for technique in techniques {
+                assert!(used.contains(technique), "no {technique} in {puzzle}");
+            }
+        }
+    }
+
+    #[test]
+    fn jellyfish_agree_with_the_solution() {
+        // the digit's other rows or columns usually form a smaller fish, found first
+        let solver = solver_without(&[
+            Technique::XWing,
+            Technique::FinnedXWing,
+            Technique::SashimiXWing,
+            Technique::Swordfish,
+            Technique::FinnedSwordfish,
+            Technique::SashimiSwordfish,
+        ]);
+        for (puzzle, technique) in [
+            (
+                "6.3.....4.29.71.38......2.........5......23...7.1..92...2....8.7..5.....1..4..7.5",
+                Technique::Jellyfish,
+            ),
+            (
+                ".9...8....3..9...8.8..4.61..74.2....3...86.....6....42......3.4...7.3...7...6.2..",
+                Technique::SashimiJellyfish,
+            ),
+        ] {
+            assert!(
+                check_steps(&solver, puzzle).contains(&technique),
+                "no {technique} in {puzzle}"
+            );
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{
+    game::{CandidateSet, Value},
+    units::{HOUSES, PEERS},
+};
+
+use super::{
+    grid::{house, pos, Grid},
+    Link, Node, Step, Technique,
+};
+
+/// A value being in a cell (on) or not (off).
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fact {
+    cell: usize,
+    value: Value,
+    on: bool,
+}
+
+/// The facts following from an assumption, each with the fact it follows from. <br>
+/// `contradiction` is the index of the fact found to contradict the assumption.
+struct Implications {
+    facts: Vec<(Fact, Option<usize>)>,
+    depth: Vec<usize>,
+    known: [[bool; 2]; 729],
+    contradiction: Option<usize>,
+}
+
+impl Implications {
+    /// Follows the singles implied by a value being in a cell, deriving facts at most
+    /// `max_length` links away from the assumption.
+    fn new(grid: &Grid, cell: usize, value: Value, max_length: usize) -> Self {
+        let mut implications = Implications {
+            facts: Vec::new(),
+            depth: Vec::new(),
+            known: [[false; 2]; 729],
+            contradiction: None,
+        };
+        let mut candidates = grid.candidates;
+        let mut on = [Value::None; 81];
+        implications.__derive(
+            Fact {
+                cell,
+                value,
+                on: true,
+            },
+            None,
+            max_length,
+        );
+
+        // the facts are appended breadth first, so they are processed in order of depth
+        let mut index = 0;
+        while index < implications.facts.len() && implications.contradiction.is_none() {
+            let (fact, _) = implications.facts[index];
+            let mut derived = Vec::new();
+            if fact.on {
+                on[fact.cell] = fact.value;
+                for other in candidates[fact.cell] - CandidateSet::single(fact.value) {
+                    derived.push(Fact {
+                        cell: fact.cell,
+                        value: other,
+                        on: false,
+                    });
+                }
+                for &peer in &PEERS[fact.cell] {
+                    if candidates[peer].contains(fact.value) {
+                        derived.push(Fact {
+                            cell: peer,
+                            value: fact.value,
+                            on: false,
+                        });
+                    }
+                }
+            } else {
+                candidates[fact.cell].remove(fact.value);
+                if on[fact.cell] == Value::None {
+                    // naked single in the cell
+                    match candidates[fact.cell].only() {
+                        Some(value) => derived.push(Fact {
+                            cell: fact.cell,
+                            value,
+                            on: true,
+                        }),
+                        None if candidates[fact.cell].is_empty() => {
+                            implications.contradiction = Some(index);
+                        }
+                        None => {}
+                    }
+                }
+                // hidden singles in the houses of the cell
+                for h in (0..27).filter(|&h| HOUSES[h].contains(&fact.cell)) {
+                    if grid.placed(h).contains(fact.value)
+                        || HOUSES[h].iter().any(|&cell| on[cell] == fact.value)
+                    {
+                        continue;
+                    }
+                    let cells = HOUSES[h]
+                        .into_iter()
+                        .filter(|&cell| candidates[cell].contains(fact.value))
+                        .collect::<Vec<_>>();
+                    match cells[..] {
+                        [] => implications.contradiction = Some(index),
+                        [cell] => derived.push(Fact {
+                            cell,
+                            value: fact.value,
+                            on: true,
+                        }),
+                        _ => {}
+                    }
+                }
+            }
+
+            for derived in derived {
+                implications.__derive(derived, Some(index), max_length);
+            }
+            index += 1;
+        }
+
+        implications
+    }
+
+    /// Adds a fact unless it is known or too far from the assumption, a fact contradicting a
+    /// known one makes the assumption false.
+    fn __derive(&mut self, fact: Fact, parent: Option<usize>, max_length: usize) {
+        let depth = parent.map_or(0, |parent| self.depth[parent] + 1);
+        if depth > max_length || self.__contains(fact) {
+            return;
+        }
+        let index = fact.cell * 9 + fact.value.to_usize() - 1;
+        if self.known[index][!fact.on as usize] && self.contradiction.is_none() {
+            self.contradiction = Some(self.facts.len());
+        }
+
+        self.known[index][fact.on as usize] = true;
+        self.facts.push((fact, parent));
+        self.depth.push(depth);
+    }
+
+    fn __contains(&self, fact: Fact) -> bool {
+        self.known[fact.cell * 9 + fact.value.to_usize() - 1][fact.on as usize]
+    }
+
+    /// Returns the links from the assumption to a fact.
+    fn links(&self, fact: Fact) -> Vec<Link> {
+        let mut links = Vec::new();
+        let Some(mut index) = self.facts.iter().position(|(other, _)| *other == fact) else {
+            return links;
+        };
+        while let (to, Some(parent)) = self.facts[index] {
+            let from = self.facts[parent].0;
+            let (from_node, to_node) = (
+                Node::cell(pos(from.cell), from.value),
+                Node::cell(pos(to.cell), to.value),
+            );
+            // an on fact turns values off (weak), an off fact turns a value on (strong)
+            links.push(if to.on {
+                Link::strong(from_node, to_node)
+            } else {
+                Link::weak(from_node, to_node)
+            });
+            index = parent;
+        }
+        links.reverse();
+        links
+    }
+}
+
+/// Assumes each possible value of a cell in turn, a fact implied by all of them is true. <br>
+/// An assumption leading to a contradiction is removed instead.
+pub(crate) fn cell_forcing_chain(grid: &Grid, max_length: usize) -> Option<Step> {
+    let mut cells = (0..81)
+        .filter(|&cell| grid.values[cell] == Value::None)
+        .collect::<Vec<_>>();
+    cells.sort_by_key(|&cell| grid.candidates[cell].len());
+
+    cells.into_iter().find_map(|cell| {
+        let branches = grid.candidates[cell]
+            .into_iter()
+            .map(|value| (cell, value))
+            .collect::<Vec<_>>();
+        let mut step = forcing(grid, &branches, max_length, Technique::CellForcingChain)?;
+        step.cells.insert(0, pos(cell));
+        Some(step)
+    })
+}
+
+/// Assumes each possible cell of a value in a house in turn, a fact implied by all of them is
+/// true. An assumption leading to a contradiction is removed instead.
+pub(crate) fn unit_forcing_chain(grid: &Grid, max_length: usize) -> Option<Step> {
+    (0..27).find_map(|h| {
+        (!grid.placed(h)).into_iter().find_map(|value| {
+            let cells = grid.cells_with(h, value);
+            if cells.len() < 2 {
+                return None;
+            }
+
+            let branches = cells.iter().map(|&cell| (cell, value)).collect::<Vec<_>>();
+            let mut step = forcing(grid, &branches, max_length, Technique::UnitForcingChain)?;
+            step.cells = cells.into_iter().map(pos).collect();
+            step.houses.push(house(h));
+            Some(step)
+        })
+    })
+}
+
+/// Returns the conclusion of a set of assumptions of which one is true.
+fn forcing(
+    grid: &Grid,
+    branches: &[(usize, Value)],
+    max_length: usize,
+    technique: Technique,
+) -> Option<Step> {
+    let implications = branches
+        .iter()
+        .map(|&(cell, value)| Implications::new(grid, cell, value, max_length))
+        .collect::<Vec<_>>();
+
+    let mut step = Step::new(
+        technique,
+        branches.iter().map(|&(_, value)| value).collect(),
+    );
+    if let Some((i, contradiction)) = implications
+        .iter()
+        .enumerate()
+        .find_map(|(i, branch)| Some((i, branch.contradiction?)))
+    {
+        // the assumption can't be true
+        let (cell, value) = branches[i];
+        let (row, col) = pos(cell);
+        step.links = implications[i].links(implications[i].facts[contradiction].0);
+        step.eliminations.push((row, col, value));
+        return Some(step);
+    }
+
+    // facts of the first branch implied by all branches, which aren't known yet
+    let (first, rest) = implications.split_first()?;
+    let common = first
+        .facts
+        .iter()
+        .map(|&(fact, _)| fact)
+        .filter(|&fact| rest.iter().all(|branch| branch.__contains(fact)))
+        .filter(|fact| grid.candidates[fact.cell].contains(fact.value))
+        .collect::<Vec<_>>();
+
+    let fact = if let Some(&fact) = common.iter().find(|fact| fact.on) {
+        let (row, col) = pos(fact.cell);
+        step.placement = Some((row, col, fact.value));
+        fact
+    } else {
+        let &fact = common.first()?;
+        step.eliminations = common
+            .iter()
+            .map(|fact| {
+                let (row, col) = pos(fact.cell);
+                (row, col, fact.value)
+            })
+            .collect();
+        fact
+    };
+    step.branches = implications
+        .iter()
+        .map(|branch| branch.links(fact))
+        .collect();
+    Some(step)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Node, Technique},
+        testing::{check_steps, find_step, solver_without},
+    };
+
+    #[test]
+    fn contradiction_removes_the_assumption() {
+        let puzzle =
+            ".9.4.6.......8.5.71...3..6.....68..2..63......39..48..86...2.7...5.7...19........";
+        assert!(check_steps(&LogicalSolver::new(), puzzle).contains(&Technique::CellForcingChain));
+
+        let (_, step) = find_step(&LogicalSolver::new(), puzzle, |step| {
+            step.technique == Technique::CellForcingChain
+        });
+        let [(row, col, value)] = step.eliminations[..] else {
+            panic!("{step}");
+        };
+        assert!(step.branches.is_empty(), "{step}");
+        assert_eq!(step.links[0].from, Node::cell((row, col), value));
+        assert!(step.explanation().starts_with("Assuming"));
+    }
+
+    #[test]
+    fn each_assumption_has_its_own_branch() {
+        let puzzle =
+            ".6....2.1......38...4.27........453....51..6......8.9..29.41...7.........8...3..6";
+        let solver = LogicalSolver::new();
+        assert!(check_steps(&solver, puzzle).contains(&Technique::CellForcingChain));
+
+        let (_, step) = find_step(&solver, puzzle, |step| {
+            step.technique == Technique::CellForcingChain && !step.branches.is_empty()
+        });
+        assert!(step.links.is_empty(), "{step}");
+        assert_eq!(step.branches.len(), step.digits.len(), "{step}");
+        for (links, value) in step.branches.iter().zip(step.digits) {
+            // a branch without links assumes the result itself
+            if let (Some(first), Some(last)) = (links.first(), links.last()) {
+                assert_eq!(first.from, Node::cell(step.cells[0], value), "{step}");
+                let (row, col, value) = step.placement.unwrap_or_else(|| step.eliminations[0]);
+                assert_eq!(last.to, Node::cell((row, col), value), "{step}");
+            }
+        }
+    }
+
+    #[test]
+    fn unit_forcing_chain_agrees_with_the_solution() {
+        // cell forcing chains come first and find most results of unit forcing chains
+        let solver = solver_without(&[Technique::CellForcingChain]);
+        let puzzle =
+            "9...6......8...63...281..4.....2.3....7....8.3.....2194....6...5....48...965.....";
+        assert!(check_steps(&solver, puzzle).contains(&Technique::UnitForcingChain));
+    }
+}
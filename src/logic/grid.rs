@@ -0,0 +1,84 @@
+use crate::{
+    game::{CandidateSet, House, SudokuBoard, Value},
+    units::HOUSES,
+};
+
+/// Snapshot of the values and possible values of a board, indexed by cell `row * 9 + col`
+/// with `row` and `col` bounds 0..9.
+pub(crate) struct Grid {
+    pub values: [Value; 81],
+    pub candidates: [CandidateSet; 81],
+}
+
+impl Grid {
+    pub fn new(board: &SudokuBoard) -> Self {
+        let mut grid = Grid {
+            values: [Value::None; 81],
+            candidates: [CandidateSet::empty(); 81],
+        };
+
+        for cell in 0..81 {
+            let (row, col) = pos(cell);
+            grid.values[cell] = board.value(row, col);
+            grid.candidates[cell] = board.candidates(row, col);
+        }
+
+        grid
+    }
+
+    /// Checks if an empty cell has no possible values left.
+    pub fn is_broken(&self) -> bool {
+        (0..81).any(|cell| self.values[cell] == Value::None && self.candidates[cell].is_empty())
+    }
+
+    /// Returns the values placed in a house.
+    pub fn placed(&self, house: usize) -> CandidateSet {
+        HOUSES[house]
+            .iter()
+            .map(|&cell| self.values[cell])
+            .filter(|&value| value != Value::None)
+            .collect()
+    }
+
+    /// Returns the cells of a house having the value as a possible value.
+    pub fn cells_with(&self, house: usize, value: Value) -> Vec<usize> {
+        HOUSES[house]
+            .iter()
+            .copied()
+            .filter(|&cell| self.candidates[cell].contains(value))
+            .collect()
+    }
+}
+
+/// Returns the (row, col) position of a cell, the position bounds are 1..=9.
+pub(crate) fn pos(cell: usize) -> (usize, usize) {
+    (cell / 9 + 1, cell % 9 + 1)
+}
+
+/// Returns the [`House`] for an index into [`HOUSES`].
+pub(crate) fn house(index: usize) -> House {
+    match index {
+        0..9 => House::Row(index + 1),
+        9..18 => House::Column(index - 8),
+        _ => House::Box(index - 17),
+    }
+}
+
+/// Returns all combinations of `k` items, keeping the order of the items.
+pub(crate) fn combinations<T: Copy>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    if items.len() < k {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    for (i, &item) in items.iter().enumerate() {
+        for mut rest in combinations(&items[i + 1..], k - 1) {
+            rest.insert(0, item);
+            result.push(rest);
+        }
+    }
+    result
+}
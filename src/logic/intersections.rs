@@ -0,0 +1,85 @@
+use crate::{
+    game::{CandidateSet, Value},
+    units::{box_of, HOUSES},
+};
+
+use super::{
+    grid::{house, pos, Grid},
+    Step, Technique,
+};
+
+/// Finds a value whose possible cells in a box all lie in one row or column (pointing),
+/// the value is removed from the rest of that line.
+pub(crate) fn pointing(grid: &Grid) -> Option<Step> {
+    (18..27).find_map(|b| {
+        (!grid.placed(b)).into_iter().find_map(|value| {
+            let cells = grid.cells_with(b, value);
+            let line = shared_line(&cells)?;
+            locked_candidates(grid, Technique::Pointing, value, &cells, b, line)
+        })
+    })
+}
+
+/// Finds a value whose possible cells in a row or column all lie in one box (claiming),
+/// the value is removed from the rest of that box.
+pub(crate) fn claiming(grid: &Grid) -> Option<Step> {
+    (0..18).find_map(|line| {
+        (!grid.placed(line)).into_iter().find_map(|value| {
+            let cells = grid.cells_with(line, value);
+            let b = shared_box(&cells)?;
+            locked_candidates(grid, Technique::Claiming, value, &cells, line, b)
+        })
+    })
+}
+
+/// Removes the value from the cells of `target` outside of the locked `cells` in `source`.
+fn locked_candidates(
+    grid: &Grid,
+    technique: Technique,
+    value: Value,
+    cells: &[usize],
+    source: usize,
+    target: usize,
+) -> Option<Step> {
+    let eliminations = HOUSES[target]
+        .into_iter()
+        .filter(|cell| !cells.contains(cell) && grid.candidates[*cell].contains(value))
+        .map(|cell| {
+            let (row, col) = pos(cell);
+            (row, col, value)
+        })
+        .collect::<Vec<_>>();
+    if eliminations.is_empty() {
+        return None;
+    }
+
+    let mut step = Step::new(technique, CandidateSet::single(value));
+    step.cells = cells.iter().map(|&cell| pos(cell)).collect();
+    step.houses = vec![house(source), house(target)];
+    step.eliminations = eliminations;
+    Some(step)
+}
+
+/// Returns the index of the row or column containing all of at least 2 cells.
+fn shared_line(cells: &[usize]) -> Option<usize> {
+    let first = *cells.first()?;
+    if cells.len() < 2 {
+        None
+    } else if cells.iter().all(|cell| cell / 9 == first / 9) {
+        Some(first / 9)
+    } else if cells.iter().all(|cell| cell % 9 == first % 9) {
+        Some(9 + first % 9)
+    } else {
+        None
+    }
+}
+
+/// Returns the index of the box containing all of at least 2 cells.
+fn shared_box(cells: &[usize]) -> Option<usize> {
+    let first = *cells.first()?;
+    if cells.len() >= 2 && cells.iter().all(|&cell| box_of(cell) == box_of(first)) {
+        Some(18 + box_of(first))
+    } else {
+        None
+    }
+}
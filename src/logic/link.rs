@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use crate::game::Value;
+
+/// A value in one cell, or in a group of cells of one house. <br>
+/// All (row, col) position bounds are 1..=9.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Node {
+    pub cells: Vec<(usize, usize)>,
+    pub value: Value,
+}
+
+impl Node {
+    /// Creates a node of a single cell.
+    pub fn cell(cell: (usize, usize), value: Value) -> Self {
+        Node {
+            cells: vec![cell],
+            value,
+        }
+    }
+
+    /// Creates a grouped node of cells sharing a house.
+    pub fn group(cells: Vec<(usize, usize)>, value: Value) -> Self {
+        Node { cells, value }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.cells[..] {
+            [cell] => write!(f, "{}{cell:?}", self.value),
+            cells => write!(f, "{}{cells:?}", self.value),
+        }
+    }
+}
+
+/// An inference between two nodes. <br>
+/// A strong link means at least one of the nodes is true, a weak link means at most one is.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Link {
+    pub from: Node,
+    pub to: Node,
+    pub strong: bool,
+}
+
+impl Link {
+    pub fn strong(from: Node, to: Node) -> Self {
+        Link {
+            from,
+            to,
+            strong: true,
+        }
+    }
+
+    pub fn weak(from: Node, to: Node) -> Self {
+        Link {
+            from,
+            to,
+            strong: false,
+        }
+    }
+}
+
+impl Display for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let symbol = if self.strong { "=" } else { "-" };
+        write!(f, "{} {symbol} {}", self.from, self.to)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::SudokuBoard;
+
+use super::{LogicalSolver, Step};
+
+/// Walks through the steps of the [`LogicalSolver`] on a board one at a time, forward and back.
+/// The board is snapshotted before each step, the original board is not modified.
+pub struct Playback {
+    boards: Vec<SudokuBoard>,
+    steps: Vec<Step>,
+    position: usize,
+}
+
+impl Playback {
+    /// Solves a copy of the board, recording each step.
+    pub fn new(board: &SudokuBoard) -> Self {
+        let solver = LogicalSolver::new();
+        let mut current = board.clone();
+        let mut boards = vec![current.clone()];
+        let mut steps = Vec::new();
+        while let Some(step) = solver.next_step(&current) {
+            if !LogicalSolver::apply(&mut current, &step) {
+                break;
+            }
+            steps.push(step);
+            boards.push(current.clone());
+        }
+
+        Playback {
+            boards,
+            steps,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the number of steps applied to the current board.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the board before the current step, or the final board at the end.
+    pub fn board(&self) -> &SudokuBoard {
+        &self.boards[self.position]
+    }
+
+    /// Returns the current step, `None` at the end.
+    pub fn step(&self) -> Option<&Step> {
+        self.steps.get(self.position)
+    }
+
+    /// Applies the current step, returns false at the end.
+    pub fn forward(&mut self) -> bool {
+        if self.position < self.steps.len() {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Undoes the previous step, returns false at the start.
+    pub fn back(&mut self) -> bool {
+        if self.position > 0 {
+            self.position -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks if the steps solve the board.
+    pub fn is_solved(&self) -> bool {
+        self.boards.last().is_some_and(|board| board.is_solved())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::Value,
+        testing::{board, TWO_SOLUTIONS, UNIQUE},
+    };
+
+    #[test]
+    fn steps_forward_and_back() {
+        let board = board(UNIQUE);
+        let mut playback = Playback::new(&board);
+        // only singles, one step for each empty cell
+        assert_eq!(playback.len(), 51);
+        assert!(playback.is_solved());
+        assert_eq!(playback.position(), 0);
+        assert!(!playback.back());
+
+        let first = playback.step().unwrap().clone();
+        let (row, col, value) = first.placement.unwrap();
+        assert_eq!(playback.board().value(row, col), Value::None);
+        assert!(playback.forward());
+        assert_eq!(playback.position(), 1);
+        assert_eq!(playback.board().value(row, col), value);
+        assert!(playback.back());
+        assert_eq!(playback.step(), Some(&first));
+        assert_eq!(playback.board().value(row, col), Value::None);
+
+        while playback.forward() {}
+        assert_eq!(playback.position(), playback.len());
+        assert!(playback.step().is_none());
+        assert!(playback.board().is_solved());
+        assert!(playback.back());
+        assert_eq!(playback.position(), playback.len() - 1);
+
+        // the original board is left as is
+        assert_eq!(board.value(row, col), Value::None);
+    }
+
+    #[test]
+    fn stuck_playback_is_not_solved() {
+        let mut playback = Playback::new(&board(TWO_SOLUTIONS));
+        assert!(!playback.is_empty());
+        assert!(!playback.is_solved());
+        while playback.forward() {}
+        assert!(!playback.board().is_solved());
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::Display;
+
+use super::{Step, Technique};
+
+/// Coarse difficulty of a puzzle, derived from its [`Rating`] score.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Grade {
+    /// Singles only.
+    Easy,
+    /// Intersections, pairs and X-Wings.
+    Medium,
+    /// Triples, fish, wings and unique rectangles.
+    Hard,
+    /// Quads, jellyfish and short X- and XY-Chains.
+    Expert,
+    /// Long chains, almost locked sets, forcing chains or beyond the logical solver.
+    Extreme,
+}
+
+impl Grade {
+    /// Returns all grades, easiest first.
+    pub fn all() -> &'static [Grade] {
+        &[
+            Grade::Easy,
+            Grade::Medium,
+            Grade::Hard,
+            Grade::Expert,
+            Grade::Extreme,
+        ]
+    }
+
+    /// Returns the grade of a score.
+    pub fn of(score: f32) -> Grade {
+        match score {
+            s if s <= 2.3 => Grade::Easy,
+            s if s <= 3.4 => Grade::Medium,
+            s if s <= 4.5 => Grade::Hard,
+            s if s <= 6.8 => Grade::Expert,
+            _ => Grade::Extreme,
+        }
+    }
+
+    /// Parses a grade name, e.g. `hard`.
+    pub fn from_name(name: &str) -> Option<Grade> {
+        Grade::all()
+            .iter()
+            .copied()
+            .find(|grade| grade.to_string() == name)
+    }
+}
+
+impl Display for Grade {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Grade::Easy => write!(f, "easy"),
+            Grade::Medium => write!(f, "medium"),
+            Grade::Hard => write!(f, "hard"),
+            Grade::Expert => write!(f, "expert"),
+            Grade::Extreme => write!(f, "extreme"),
+        }
+    }
+}
+
+/// Difficulty of a puzzle, created by [`SudokuBoard::rate`](crate::SudokuBoard::rate).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rating {
+    /// Rating of the hardest step, or [`Rating::UNSOLVED`] if the logical solver got stuck.
+    pub score: f32,
+    pub grade: Grade,
+    /// The technique of the hardest step, if any.
+    pub hardest: Option<Technique>,
+    /// The number of steps the logical solver took.
+    pub steps: usize,
+    /// Whether the logical solver solved the puzzle.
+    pub solved: bool,
+}
+
+impl Rating {
+    /// The score of puzzles the logical solver can't solve, above all techniques.
+    pub const UNSOLVED: f32 = 10.0;
+
+    /// Rates a puzzle by the steps the logical solver took on it.
+    pub fn new(steps: &[Step], solved: bool) -> Self {
+        let hardest = steps
+            .iter()
+            .max_by(|a, b| a.rating().total_cmp(&b.rating()));
+        let score = match hardest {
+            _ if !solved => Rating::UNSOLVED,
+            Some(step) => (step.rating() * 10.0).round() / 10.0,
+            None => 0.0,
+        };
+
+        Rating {
+            score,
+            grade: Grade::of(score),
+            hardest: hardest.map(|step| step.technique),
+            steps: steps.len(),
+            solved,
+        }
+    }
+}
+
+impl Display for Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Rating {:.1} ({})", self.score, self.grade)?;
+        if let Some(technique) = self.hardest {
+            write!(f, ", hardest step {technique}")?;
+        }
+        if !self.solved {
+            write!(
+                f,
+                ", the logical solver got stuck after {} steps",
+                self.steps
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{CandidateSet, Value},
+        logic::{Link, Node},
+        testing::{board, TWO_SOLUTIONS, UNIQUE},
+    };
+
+    #[test]
+    fn grade_boundaries() {
+        for (score, grade) in [
+            (0.0, Grade::Easy),
+            (2.3, Grade::Easy),
+            (2.4, Grade::Medium),
+            (3.4, Grade::Medium),
+            (3.5, Grade::Hard),
+            (4.5, Grade::Hard),
+            (4.6, Grade::Expert),
+            (6.8, Grade::Expert),
+            (6.9, Grade::Extreme),
+            (Rating::UNSOLVED, Grade::Extreme),
+        ] {
+            assert_eq!(Grade::of(score), grade, "{score}");
+        }
+    }
+
+    #[test]
+    fn short_chains_are_expert() {
+        let chain = |length| {
+            let mut step = Step::new(Technique::XYChain, CandidateSet::empty());
+            let node = Node::cell((1, 1), Value::One);
+            step.links = vec![Link::strong(node.clone(), node); length];
+            Rating::new(&[step], true).grade
+        };
+        // an XY-Chain through 3 cells is an XY-Wing, through 4 cells it has 7 links
+        assert_eq!(chain(7), Grade::Expert);
+        assert_eq!(chain(8), Grade::Expert);
+        assert_eq!(chain(9), Grade::Extreme);
+    }
+
+    #[test]
+    fn rate_known_puzzles() {
+        let rating = board(UNIQUE).rate();
+        assert_eq!(
+            rating,
+            Rating {
+                score: 1.5,
+                grade: Grade::Easy,
+                hardest: Some(Technique::HiddenSingle),
+                steps: 51,
+                solved: true,
+            }
+        );
+
+        let rating = board(
+            "1....5.....97...4......9.......5.76.2.7......69.2....1.34.2.8.79.6.7..2..7.......",
+        )
+        .rate();
+        assert_eq!(
+            (rating.score, rating.grade, rating.hardest),
+            (6.8, Grade::Expert, Some(Technique::XYChain))
+        );
+
+        // uniqueness techniques are skipped and the logical solver gets stuck
+        let rating = board(TWO_SOLUTIONS).rate();
+        assert!(!rating.solved);
+        assert_eq!(
+            (rating.score, rating.grade),
+            (Rating::UNSOLVED, Grade::Extreme)
+        );
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{
+    game::{CandidateSet, Value},
+    units::{box_of, sees, HOUSES},
+};
+
+use super::{
+    grid::{house, pos, Grid},
+    Link, Node, Step, Technique,
+};
+
+/// Two cells of a house that are the only possible cells of a value in it (conjugate pair).
+#[derive(Clone, Copy)]
+struct Pair {
+    house: usize,
+    cells: [usize; 2],
+}
+
+/// Returns the conjugate pairs of a value in all houses.
+fn conjugate_pairs(grid: &Grid, value: Value) -> Vec<Pair> {
+    (0..27)
+        .filter_map(|h| match grid.cells_with(h, value)[..] {
+            [a, b] => Some(Pair {
+                house: h,
+                cells: [a, b],
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Finds two conjugate pairs in parallel rows (or columns) with one end of each in the same
+/// column (or row), the value is removed from the cells seeing both other ends.
+pub(crate) fn skyscraper(grid: &Grid) -> Option<Step> {
+    (1..=9).map(Value::from_usize).find_map(|value| {
+        let pairs = conjugate_pairs(grid, value);
+        pairs.iter().enumerate().find_map(|(i, first)| {
+            pairs[i + 1..].iter().find_map(|second| {
+                // the line crossing both pairs
+                let line = match (first.house, second.house) {
+                    (0..9, 0..9) => |cell: usize| cell % 9,
+                    (9..18, 9..18) => |cell: usize| cell / 9,
+                    _ => return None,
+                };
+
+                ends(first, second)
+                    .into_iter()
+                    .find_map(|([far1, near1], [near2, far2])| {
+                        if line(near1) != line(near2) || line(far1) == line(far2) {
+                            return None;
+                        }
+
+                        let eliminations =
+                            seen_by_both(grid, value, far1, far2, &[far1, near1, near2, far2]);
+                        if eliminations.is_empty() {
+                            return None;
+                        }
+
+                        let mut step =
+                            Step::new(Technique::Skyscraper, CandidateSet::single(value));
+                        step.cells = [far1, near1, near2, far2].into_iter().map(pos).collect();
+                        step.houses = vec![house(first.house), house(second.house)];
+                        step.links = vec![
+                            Link::strong(node(far1, value), node(near1, value)),
+                            Link::weak(node(near1, value), node(near2, value)),
+                            Link::strong(node(near2, value), node(far2, value)),
+                        ];
+                        step.eliminations = eliminations;
+                        Some(step)
+                    })
+            })
+        })
+    })
+}
+
+/// Finds a conjugate pair in a row and one in a column with one end of each in the same box,
+/// the value is removed from the cells seeing both other ends.
+pub(crate) fn two_string_kite(grid: &Grid) -> Option<Step> {
+    (1..=9).map(Value::from_usize).find_map(|value| {
+        let pairs = conjugate_pairs(grid, value);
+        let rows = pairs.iter().filter(|pair| pair.house < 9);
+        let cols = pairs.iter().filter(|pair| (9..18).contains(&pair.house));
+        rows.flat_map(|row| cols.clone().map(move |col| (row, col)))
+            .find_map(|(row, col)| {
+                ends(row, col)
+                    .into_iter()
+                    .find_map(|([far1, near1], [near2, far2])| {
+                        let cells = [far1, near1, near2, far2];
+                        if box_of(near1) != box_of(near2)
+                            || (0..4).any(|i| cells[i + 1..].contains(&cells[i]))
+                        {
+                            return None;
+                        }
+
+                        let eliminations = seen_by_both(grid, value, far1, far2, &cells);
+                        if eliminations.is_empty() {
+                            return None;
+                        }
+
+                        let mut step =
+                            Step::new(Technique::TwoStringKite, CandidateSet::single(value));
+                        step.cells = cells.into_iter().map(pos).collect();
+                        step.houses = vec![
+                            house(row.house),
+                            house(col.house),
+                            house(18 + box_of(near1)),
+                        ];
+                        step.links = vec![
+                            Link::strong(node(far1, value), node(near1, value)),
+                            Link::weak(node(near1, value), node(near2, value)),
+                            Link::strong(node(near2, value), node(far2, value)),
+                        ];
+                        step.eliminations = eliminations;
+                        Some(step)
+                    })
+            })
+    })
+}
+
+/// Finds a box whose possible cells of a value lie in one row and one column of it, and a
+/// conjugate pair with one end in that column (or row) outside the box. <br>
+/// Either the box or the other end holds the value, so it is removed from the cell seeing both.
+pub(crate) fn empty_rectangle(grid: &Grid) -> Option<Step> {
+    (1..=9).map(Value::from_usize).find_map(|value| {
+        let pairs = conjugate_pairs(grid, value);
+        (18..27).find_map(|b| {
+            let cells = grid.cells_with(b, value);
+            if cells.len() < 2 {
+                return None;
+            }
+            let (top, left) = (HOUSES[b][0] / 9, HOUSES[b][0] % 9);
+
+            (top..top + 3)
+                .flat_map(|row| (left..left + 3).map(move |col| (row, col)))
+                .find_map(|(row, col)| {
+                    let row_part = cells.iter().copied().filter(|cell| cell / 9 == row);
+                    let col_part = cells.iter().copied().filter(|cell| cell % 9 == col);
+                    let (row_part, col_part) =
+                        (row_part.collect::<Vec<_>>(), col_part.collect::<Vec<_>>());
+                    if cells.iter().any(|cell| cell / 9 != row && cell % 9 != col)
+                        || row_part.len() == cells.len()
+                        || col_part.len() == cells.len()
+                    {
+                        return None;
+                    }
+
+                    pairs.iter().find_map(|pair| {
+                        // a pair in a row outside the band with one end in the column, or a
+                        // pair in a column outside the stack with one end in the row
+                        let (near, far, target, arms) = match pair.house {
+                            0..9 if pair.house / 3 != top / 3 => {
+                                let [near, far] = oriented(pair, |cell| cell % 9 == col)?;
+                                if far % 9 / 3 == left / 3 {
+                                    return None;
+                                }
+                                (near, far, row * 9 + far % 9, [&col_part, &row_part])
+                            }
+                            9..18 if (pair.house - 9) / 3 != left / 3 => {
+                                let [near, far] = oriented(pair, |cell| cell / 9 == row)?;
+                                if far / 9 / 3 == top / 3 {
+                                    return None;
+                                }
+                                (near, far, far / 9 * 9 + col, [&row_part, &col_part])
+                            }
+                            _ => return None,
+                        };
+                        if !grid.candidates[target].contains(value) {
+                            return None;
+                        }
+
+                        let group = |cells: &[usize]| {
+                            Node::group(cells.iter().map(|&cell| pos(cell)).collect(), value)
+                        };
+                        let mut step =
+                            Step::new(Technique::EmptyRectangle, CandidateSet::single(value));
+                        step.cells = cells
+                            .iter()
+                            .chain([near, far].iter())
+                            .map(|&c| pos(c))
+                            .collect();
+                        step.houses = vec![house(b), house(pair.house)];
+                        step.links = vec![
+                            Link::strong(node(far, value), node(near, value)),
+                            Link::weak(node(near, value), group(arms[0])),
+                            Link::strong(group(arms[0]), group(arms[1])),
+                        ];
+                        let (target_row, target_col) = pos(target);
+                        step.eliminations = vec![(target_row, target_col, value)];
+                        Some(step)
+                    })
+                })
+        })
+    })
+}
+
+/// Colors the cells of chains of conjugate pairs of a value alternately. <br>
+/// If two cells of one color see each other that color is false (color wrap), else the value
+/// is removed from the cells seeing both colors (color trap).
+pub(crate) fn simple_coloring(grid: &Grid) -> Option<Step> {
+    (1..=9).map(Value::from_usize).find_map(|value| {
+        let pairs = conjugate_pairs(grid, value);
+        let mut color = [None; 81];
+
+        for start in pairs.iter().map(|pair| pair.cells[0]) {
+            if color[start].is_some() {
+                continue;
+            }
+
+            // breadth first search, remembering the links used to color each cell
+            let mut links = Vec::new();
+            let mut component = vec![start];
+            color[start] = Some(false);
+            let mut i = 0;
+            while i < component.len() {
+                let cell = component[i];
+                for pair in &pairs {
+                    let Some(j) = pair.cells.iter().position(|&c| c == cell) else {
+                        continue;
+                    };
+                    let other = pair.cells[1 - j];
+                    if color[other].is_none() {
+                        color[other] = color[cell].map(|c| !c);
+                        component.push(other);
+                        links.push(Link::strong(node(cell, value), node(other, value)));
+                    }
+                }
+                i += 1;
+            }
+
+            let colored = |c: bool| {
+                component
+                    .iter()
+                    .copied()
+                    .filter(move |&cell| color[cell] == Some(c))
+            };
+            let wrapped = [false, true]
+                .into_iter()
+                .find(|&c| colored(c).any(|a| colored(c).any(|b| a != b && sees(a, b))));
+            let eliminations = match wrapped {
+                Some(c) => colored(c).collect::<Vec<_>>(),
+                None => (0..81)
+                    .filter(|cell| {
+                        grid.candidates[*cell].contains(value) && !component.contains(cell)
+                    })
+                    .filter(|&cell| {
+                        colored(false).any(|a| sees(a, cell))
+                            && colored(true).any(|b| sees(b, cell))
+                    })
+                    .collect(),
+            };
+            if eliminations.is_empty() {
+                continue;
+            }
+
+            let mut step = Step::new(Technique::SimpleColoring, CandidateSet::single(value));
+            step.cells = colored(false).chain(colored(true)).map(pos).collect();
+            step.links = links;
+            step.eliminations = eliminations
+                .into_iter()
+                .map(|cell| {
+                    let (row, col) = pos(cell);
+                    (row, col, value)
+                })
+                .collect();
+            return Some(step);
+        }
+        None
+    })
+}
+
+/// Returns the 4 ways to connect two pairs as ([far, near], [near, far]).
+fn ends(first: &Pair, second: &Pair) -> [([usize; 2], [usize; 2]); 4] {
+    let [a, b] = first.cells;
+    let [c, d] = second.cells;
+    [
+        ([a, b], [c, d]),
+        ([a, b], [d, c]),
+        ([b, a], [c, d]),
+        ([b, a], [d, c]),
+    ]
+}
+
+/// Returns the cells of a pair as [near, far] if exactly one of them is near.
+fn oriented(pair: &Pair, near: impl Fn(usize) -> bool) -> Option<[usize; 2]> {
+    let [a, b] = pair.cells;
+    match (near(a), near(b)) {
+        (true, false) => Some([a, b]),
+        (false, true) => Some([b, a]),
+        _ => None,
+    }
+}
+
+/// Returns the removals of the value from the cells seeing both cells, except the pattern.
+fn seen_by_both(
+    grid: &Grid,
+    value: Value,
+    a: usize,
+    b: usize,
+    pattern: &[usize],
+) -> Vec<(usize, usize, Value)> {
+    (0..81)
+        .filter(|cell| !pattern.contains(cell) && grid.candidates[*cell].contains(value))
+        .filter(|&cell| sees(cell, a) && sees(cell, b))
+        .map(|cell| {
+            let (row, col) = pos(cell);
+            (row, col, value)
+        })
+        .collect()
+}
+
+fn node(cell: usize, value: Value) -> Node {
+    Node::cell(pos(cell), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Technique},
+        testing::check_steps,
+    };
+
+    #[test]
+    fn single_digit_patterns_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for (puzzle, technique) in [
+            (
+                "51.7...82..2.95..3.....14.5..4.7..1.387......2........4...5.3..........897..4.5..",
+                Technique::Skyscraper,
+            ),
+            (
+                "..437.5....6.2.3....3....214.27...6.18...5.3..7..4.18...8.5.......6.........3...7",
+                Technique::TwoStringKite,
+            ),
+            (
+                ".316..4..2.6..4.9...9.3.5..91..........9.......7.82......4.938....5....4..5..312.",
+                Technique::EmptyRectangle,
+            ),
+            (
+                ".45....7....8..3..8...3..92.2...1.....634.........2..5......2....34.9...97.2....1",
+                Technique::SimpleColoring,
+            ),
+        ] {
+            assert!(
+                check_steps(&solver, puzzle).contains(&technique),
+                "no {technique} in {puzzle}"
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::game::{CandidateSet, Value};
+
+use super::{
+    grid::{house, pos, Grid},
+    Step, Technique,
+};
+
+/// Finds an empty cell with a single possible value.
+pub(crate) fn naked_single(grid: &Grid) -> Option<Step> {
+    (0..81).find_map(|cell| {
+        let value = grid.candidates[cell].only()?;
+        if grid.values[cell] != Value::None {
+            return None;
+        }
+
+        let mut step = Step::new(Technique::NakedSingle, CandidateSet::single(value));
+        step.cells.push(pos(cell));
+        let (row, col) = pos(cell);
+        step.placement = Some((row, col, value));
+        Some(step)
+    })
+}
+
+/// Finds a value with a single possible cell in a box, row or column, boxes are searched first.
+pub(crate) fn hidden_single(grid: &Grid) -> Option<Step> {
+    (18..27).chain(0..18).find_map(|h| {
+        let missing = !grid.placed(h);
+        missing.iter().find_map(|value| {
+            let cells = grid.cells_with(h, value);
+            if cells.len() != 1 {
+                return None;
+            }
+
+            let mut step = Step::new(Technique::HiddenSingle, CandidateSet::single(value));
+            let (row, col) = pos(cells[0]);
+            step.cells.push((row, col));
+            step.houses.push(house(h));
+            step.placement = Some((row, col, value));
+            Some(step)
+        })
+    })
+}
@@ -0,0 +1,283 @@
+use std::fmt::Display;
+
+use crate::game::{CandidateSet, House, Value};
+
+use super::{Link, Node, Technique};
+
+/// A single deduction of the [`LogicalSolver`](super::LogicalSolver). <br>
+/// All (row, col) position bounds are 1..=9.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Step {
+    pub technique: Technique,
+    /// The digits the deduction is about.
+    pub digits: CandidateSet,
+    /// The cells forming the pattern.
+    pub cells: Vec<(usize, usize)>,
+    /// The houses the pattern lies in.
+    pub houses: Vec<House>,
+    /// The fins of a finned or sashimi fish.
+    pub fins: Vec<(usize, usize)>,
+    /// The pivot cell of a wing, or the stem cell of a death blossom.
+    pub pivot: Option<(usize, usize)>,
+    /// The pincer cells of a wing, the removed value is in one of them.
+    pub pincers: Vec<(usize, usize)>,
+    /// The links the deduction follows, in order.
+    pub links: Vec<Link>,
+    /// The links followed from each assumption of a forcing chain to the common result, in
+    /// order of the assumptions. Empty for other deductions.
+    pub branches: Vec<Vec<Link>>,
+    /// The almost locked sets the deduction uses.
+    pub sets: Vec<Vec<(usize, usize)>>,
+    /// The value placed as (row, col, value), if any.
+    pub placement: Option<(usize, usize, Value)>,
+    /// The possible values removed as (row, col, value).
+    pub eliminations: Vec<(usize, usize, Value)>,
+}
+
+impl Step {
+    /// Creates a step without cells, houses, placement or eliminations.
+    pub fn new(technique: Technique, digits: CandidateSet) -> Self {
+        Step {
+            technique,
+            digits,
+            cells: Vec::new(),
+            houses: Vec::new(),
+            fins: Vec::new(),
+            pivot: None,
+            pincers: Vec::new(),
+            links: Vec::new(),
+            branches: Vec::new(),
+            sets: Vec::new(),
+            placement: None,
+            eliminations: Vec::new(),
+        }
+    }
+
+    /// Checks if the step removes the assumption its links start from.
+    fn __is_contradiction(&self) -> bool {
+        match (&self.eliminations[..], self.links.first()) {
+            ([(row, col, value)], Some(link)) => link.from == Node::cell((*row, *col), *value),
+            _ => false,
+        }
+    }
+
+    /// Returns the difficulty of the step, the rating of its technique plus 0.1 for each
+    /// time the number of links, over all branches of a forcing chain, exceeds the next of
+    /// 4, 6, 8, 12, 16, 24, ...
+    pub fn rating(&self) -> f32 {
+        let length = self.links.len() + self.branches.iter().map(Vec::len).sum::<usize>();
+        let mut rating = self.technique.rating();
+        let mut limit = 4;
+        while length > limit {
+            rating += 0.1;
+            limit = if limit.is_power_of_two() {
+                limit * 3 / 2
+            } else {
+                limit * 4 / 3
+            };
+        }
+        rating
+    }
+
+    /// Returns the cells the step places a value in or removes possible values from.
+    pub fn affected_cells(&self) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        let placed = self.placement.map(|(row, col, _)| (row, col));
+        for cell in placed
+            .into_iter()
+            .chain(self.eliminations.iter().map(|&(r, c, _)| (r, c)))
+        {
+            if !cells.contains(&cell) {
+                cells.push(cell);
+            }
+        }
+        cells
+    }
+
+    /// Returns where to look for the step in plain English, e.g. `Look at row 1 and box 2`.
+    pub fn region(&self) -> String {
+        if self.houses.is_empty() {
+            format!("Look at {}", __cells(&self.cells))
+        } else {
+            format!(
+                "Look at {}",
+                __and(self.houses.iter().map(|house| house.to_string()))
+            )
+        }
+    }
+
+    /// Returns the full deduction in plain English.
+    pub fn explanation(&self) -> String {
+        let cells = __cells(&self.cells);
+        let houses = __and(self.houses.iter().map(|house| house.to_string()));
+        let removed = self.eliminations.first().map(|&(_, _, value)| value);
+        let digits = __and(self.digits.iter().map(|value| value.to_string()));
+        let links = __and(self.links.iter().map(|link| link.to_string()));
+
+        let mut reason = match self.technique {
+            Technique::HiddenSingle => format!("In {houses}, {digits} can only go in {cells}."),
+            Technique::NakedSingle => format!("{cells} has {digits} as its only possible value."),
+            Technique::Pointing | Technique::Claiming => format!(
+                "In {}, {digits} can only go in {cells}, which all lie in {}.",
+                self.houses[0], self.houses[1]
+            ),
+            Technique::NakedPair | Technique::NakedTriple | Technique::NakedQuad => {
+                format!("The cells {cells} of {houses} can only hold {digits} between them.")
+            }
+            Technique::HiddenPair | Technique::HiddenTriple | Technique::HiddenQuad => {
+                format!("In {houses}, {digits} can only go in {cells}.")
+            }
+            Technique::XWing
+            | Technique::FinnedXWing
+            | Technique::SashimiXWing
+            | Technique::Swordfish
+            | Technique::FinnedSwordfish
+            | Technique::SashimiSwordfish
+            | Technique::Jellyfish
+            | Technique::FinnedJellyfish
+            | Technique::SashimiJellyfish => {
+                let (base, cover) = self.houses.split_at(self.houses.len() / 2);
+                let mut reason = format!(
+                    "In {}, {digits} can only go in {}",
+                    __and(base.iter().map(|house| house.to_string())),
+                    __and(cover.iter().map(|house| house.to_string()))
+                );
+                if !self.fins.is_empty() {
+                    reason += &format!(
+                        " or in the fins at {}, which share a box",
+                        __cells(&self.fins)
+                    );
+                }
+                reason + "."
+            }
+            Technique::XYWing | Technique::XYZWing => format!(
+                "The pivot {:?} sees the pincers {}, whatever the pivot is, one of the pincers is {}.",
+                self.pivot.unwrap_or_default(),
+                __cells(&self.pincers),
+                removed.map_or(String::new(), |value| value.to_string())
+            ),
+            Technique::WWing => format!(
+                "The pincers {} both only hold {digits} and are connected through {houses}, so one of them is {}.",
+                __cells(&self.pincers),
+                removed.map_or(String::new(), |value| value.to_string())
+            ),
+            Technique::UniqueRectangleType1
+            | Technique::UniqueRectangleType2
+            | Technique::UniqueRectangleType3
+            | Technique::UniqueRectangleType4 => format!(
+                "The cells {} must not end up with only the same two values, as their places could be swapped for a second solution.",
+                __cells(&self.cells[..4])
+            ),
+            Technique::BugPlusOne => format!(
+                "Every empty cell but {cells} has two possible values, any value but {digits} there would give two solutions."
+            ),
+            Technique::SimpleColoring => format!(
+                "Coloring the cells along the links {links} alternately, one of the colors is {digits}."
+            ),
+            Technique::AlsXz | Technique::AlsXyWing | Technique::DeathBlossom => format!(
+                "The almost locked sets {} are connected by {links}, so one of them holds the value.",
+                __and(self.sets.iter().map(|set| format!("{set:?}")))
+            ),
+            Technique::CellForcingChain | Technique::UnitForcingChain
+                if self.__is_contradiction() =>
+            {
+                format!("Assuming {} leads to a contradiction following {links}.", self.links[0].from)
+            }
+            Technique::CellForcingChain | Technique::UnitForcingChain => {
+                let branches = self.branches.iter().map(|links| match links.first() {
+                    Some(link) => format!(
+                        "{} leads to it following {}",
+                        link.from,
+                        __and(links.iter().map(|link| link.to_string()))
+                    ),
+                    // the assumption is the result
+                    None => self.placement.map_or(String::new(), |(row, col, value)| {
+                        format!("{} is the result itself", Node::cell((row, col), value))
+                    }),
+                });
+                format!(
+                    "Trying each possibility of {digits} in {cells} in turn, they all lead to the same result: {}.",
+                    branches.collect::<Vec<_>>().join("; ")
+                )
+            }
+            _ => format!(
+                "Following {links}, where = means one of the two is true and - means at most one is, one end of the chain is true."
+            ),
+        };
+
+        if let Some((row, col, value)) = self.placement {
+            reason += &format!(" So {:?} is {value}.", (row, col));
+        } else {
+            let values = self
+                .digits
+                .iter()
+                .chain(CandidateSet::full() - self.digits)
+                .filter_map(|value| {
+                    let cells = self
+                        .eliminations
+                        .iter()
+                        .filter(|(_, _, v)| *v == value)
+                        .map(|&(row, col, _)| (row, col))
+                        .collect::<Vec<_>>();
+                    (!cells.is_empty()).then(|| format!("{value} from {}", __cells(&cells)))
+                });
+            reason += &format!(" So remove {}.", __and(values));
+        }
+        reason
+    }
+}
+
+/// Lists cells as `(1, 2), (3, 4) and (5, 6)`.
+fn __cells(cells: &[(usize, usize)]) -> String {
+    __and(cells.iter().map(|cell| format!("{cell:?}")))
+}
+
+/// Joins items with commas and a final `and`.
+fn __and(items: impl Iterator<Item = String>) -> String {
+    let items = items.collect::<Vec<_>>();
+    match items.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: ", self.technique)?;
+        if let Some((row, col, value)) = self.placement {
+            write!(f, "{value} at {:?}", (row, col))?;
+            if let Some(house) = self.houses.first() {
+                write!(f, " in {house}")?;
+            }
+        } else {
+            write!(f, "{} at {:?}", self.digits, self.cells)?;
+            for (i, house) in self.houses.iter().enumerate() {
+                write!(f, "{} {house}", if i == 0 { " in" } else { "," })?;
+            }
+            if !self.fins.is_empty() {
+                write!(f, " with fins at {:?}", self.fins)?;
+            }
+            if let Some(pivot) = self.pivot {
+                write!(f, " with pivot {pivot:?}")?;
+            }
+            if !self.pincers.is_empty() {
+                write!(f, " with pincers {:?}", self.pincers)?;
+            }
+            for (i, link) in self.links.iter().enumerate() {
+                write!(f, "{} {link}", if i == 0 { " via" } else { "," })?;
+            }
+            for (i, links) in self.branches.iter().enumerate() {
+                write!(f, "{}", if i == 0 { " via" } else { ";" })?;
+                for (j, link) in links.iter().enumerate() {
+                    write!(f, "{} {link}", if j == 0 { "" } else { "," })?;
+                }
+            }
+            write!(f, " removes")?;
+            for (row, col, value) in &self.eliminations {
+                write!(f, " {value} from {:?}", (row, col))?;
+            }
+        }
+        Ok(())
+    }
+}
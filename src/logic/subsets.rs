@@ -0,0 +1,147 @@
+use crate::{
+    game::{CandidateSet, Value},
+    units::HOUSES,
+};
+
+use super::{
+    grid::{combinations, house, pos, Grid},
+    Step, Technique,
+};
+
+/// Finds `size` empty cells of a house with only `size` possible values between them,
+/// these values are removed from the other cells of the house.
+pub(crate) fn naked_subset(grid: &Grid, size: usize, technique: Technique) -> Option<Step> {
+    (0..27).find_map(|h| {
+        let empty = HOUSES[h]
+            .into_iter()
+            .filter(|&cell| grid.values[cell] == Value::None)
+            .collect::<Vec<_>>();
+        let candidates = empty
+            .iter()
+            .copied()
+            .filter(|&cell| (2..=size).contains(&grid.candidates[cell].len()))
+            .collect::<Vec<_>>();
+
+        combinations(&candidates, size)
+            .into_iter()
+            .find_map(|cells| {
+                let digits = cells.iter().fold(CandidateSet::empty(), |digits, &cell| {
+                    digits | grid.candidates[cell]
+                });
+                if digits.len() != size {
+                    return None;
+                }
+
+                let eliminations = empty
+                    .iter()
+                    .filter(|cell| !cells.contains(cell))
+                    .flat_map(|&cell| {
+                        (grid.candidates[cell] & digits)
+                            .into_iter()
+                            .map(move |value| {
+                                let (row, col) = pos(cell);
+                                (row, col, value)
+                            })
+                    })
+                    .collect::<Vec<_>>();
+                if eliminations.is_empty() {
+                    return None;
+                }
+
+                let mut step = Step::new(technique, digits);
+                step.cells = cells.iter().map(|&cell| pos(cell)).collect();
+                step.houses.push(house(h));
+                step.eliminations = eliminations;
+                Some(step)
+            })
+    })
+}
+
+/// Finds `size` values of a house with only `size` possible cells between them,
+/// the other possible values are removed from these cells.
+pub(crate) fn hidden_subset(grid: &Grid, size: usize, technique: Technique) -> Option<Step> {
+    (0..27).find_map(|h| {
+        let digits = (!grid.placed(h))
+            .into_iter()
+            .filter(|&value| (1..=size).contains(&grid.cells_with(h, value).len()))
+            .collect::<Vec<_>>();
+
+        combinations(&digits, size).into_iter().find_map(|subset| {
+            let digits = subset.into_iter().collect::<CandidateSet>();
+            let mut cells = HOUSES[h]
+                .into_iter()
+                .filter(|&cell| !(grid.candidates[cell] & digits).is_empty())
+                .collect::<Vec<_>>();
+            if cells.len() != size {
+                return None;
+            }
+            cells.sort();
+
+            let eliminations = cells
+                .iter()
+                .flat_map(|&cell| {
+                    (grid.candidates[cell] - digits)
+                        .into_iter()
+                        .map(move |value| {
+                            let (row, col) = pos(cell);
+                            (row, col, value)
+                        })
+                })
+                .collect::<Vec<_>>();
+            if eliminations.is_empty() {
+                return None;
+            }
+
+            let mut step = Step::new(technique, digits);
+            step.cells = cells.iter().map(|&cell| pos(cell)).collect();
+            step.houses.push(house(h));
+            step.eliminations = eliminations;
+            Some(step)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Technique},
+        testing::{check_steps, solver_without},
+    };
+
+    #[test]
+    fn triples_and_quads_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for (puzzle, technique) in [
+            (
+                "..8.......3.....4.5.6...3..8..46.12.92.3...5......1.7....8.62.....9.2.37.5..7....",
+                Technique::NakedTriple,
+            ),
+            (
+                ".6.2.......3.......2..3..8.2.73...5..4....87.1.....4..9.4..2....7...6..85...4.7.1",
+                Technique::HiddenTriple,
+            ),
+            (
+                "94....3.......7.....19..6....51.8......4....7..8..61...6..3...8.8...47...73..126.",
+                Technique::NakedQuad,
+            ),
+        ] {
+            assert!(
+                check_steps(&solver, puzzle).contains(&technique),
+                "no {technique} in {puzzle}"
+            );
+        }
+    }
+
+    #[test]
+    fn hidden_quad_agrees_with_the_solution() {
+        // the other empty cells of a house with a hidden quad form a naked subset, found first
+        let solver = solver_without(&[
+            Technique::NakedPair,
+            Technique::NakedTriple,
+            Technique::NakedQuad,
+        ]);
+        let puzzle =
+            "5.8.....9.4..9..8.....3.....7....5.......29..26..51....5..4...8..9....7.1..6...2.";
+        assert!(check_steps(&solver, puzzle).contains(&Technique::HiddenQuad));
+    }
+}
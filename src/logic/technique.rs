@@ -0,0 +1,196 @@
+use std::fmt::Display;
+
+/// A logical solving technique, ordered by difficulty.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Technique {
+    HiddenSingle,
+    NakedSingle,
+    Pointing,
+    Claiming,
+    NakedPair,
+    XWing,
+    HiddenPair,
+    Skyscraper,
+    TwoStringKite,
+    FinnedXWing,
+    SashimiXWing,
+    NakedTriple,
+    Swordfish,
+    HiddenTriple,
+    FinnedSwordfish,
+    SashimiSwordfish,
+    EmptyRectangle,
+    SimpleColoring,
+    XYWing,
+    XYZWing,
+    WWing,
+    UniqueRectangleType1,
+    UniqueRectangleType2,
+    UniqueRectangleType3,
+    UniqueRectangleType4,
+    NakedQuad,
+    Jellyfish,
+    HiddenQuad,
+    BugPlusOne,
+    FinnedJellyfish,
+    SashimiJellyfish,
+    XChain,
+    XYChain,
+    Aic,
+    AlsXz,
+    AlsXyWing,
+    DeathBlossom,
+    CellForcingChain,
+    UnitForcingChain,
+}
+
+impl Technique {
+    /// Checks if the technique assumes the board has a unique solution.
+    pub fn requires_uniqueness(&self) -> bool {
+        matches!(
+            self,
+            Technique::UniqueRectangleType1
+                | Technique::UniqueRectangleType2
+                | Technique::UniqueRectangleType3
+                | Technique::UniqueRectangleType4
+                | Technique::BugPlusOne
+        )
+    }
+
+    /// Returns the difficulty of the technique, on a scale similar to Sudoku Explainer. <br>
+    /// Chains get harder with their length, see [`Step::rating`](super::Step::rating).
+    pub fn rating(&self) -> f32 {
+        match self {
+            Technique::HiddenSingle => 1.5,
+            Technique::NakedSingle => 2.3,
+            Technique::Pointing => 2.6,
+            Technique::Claiming => 2.8,
+            Technique::NakedPair => 3.0,
+            Technique::XWing => 3.2,
+            Technique::HiddenPair => 3.4,
+            Technique::Skyscraper => 3.5,
+            Technique::TwoStringKite => 3.5,
+            Technique::FinnedXWing => 3.5,
+            Technique::SashimiXWing => 3.5,
+            Technique::NakedTriple => 3.6,
+            Technique::Swordfish => 3.8,
+            Technique::HiddenTriple => 4.0,
+            Technique::FinnedSwordfish => 4.0,
+            Technique::SashimiSwordfish => 4.0,
+            Technique::EmptyRectangle => 4.2,
+            Technique::SimpleColoring => 4.2,
+            Technique::XYWing => 4.2,
+            Technique::XYZWing => 4.4,
+            Technique::WWing => 4.4,
+            Technique::UniqueRectangleType1 => 4.5,
+            Technique::UniqueRectangleType2 => 4.5,
+            Technique::UniqueRectangleType3 => 4.5,
+            Technique::UniqueRectangleType4 => 4.5,
+            Technique::NakedQuad => 5.0,
+            Technique::Jellyfish => 5.2,
+            Technique::HiddenQuad => 5.4,
+            Technique::BugPlusOne => 5.6,
+            Technique::FinnedJellyfish => 5.6,
+            Technique::SashimiJellyfish => 5.6,
+            Technique::XChain => 6.5,
+            Technique::XYChain => 6.6,
+            Technique::Aic => 7.0,
+            Technique::AlsXz => 7.1,
+            Technique::AlsXyWing => 7.3,
+            Technique::DeathBlossom => 7.5,
+            Technique::CellForcingChain => 8.2,
+            Technique::UnitForcingChain => 8.5,
+        }
+    }
+
+    /// Returns all techniques, easiest first.
+    pub fn all() -> &'static [Technique] {
+        &[
+            Technique::HiddenSingle,
+            Technique::NakedSingle,
+            Technique::Pointing,
+            Technique::Claiming,
+            Technique::NakedPair,
+            Technique::XWing,
+            Technique::HiddenPair,
+            Technique::Skyscraper,
+            Technique::TwoStringKite,
+            Technique::FinnedXWing,
+            Technique::SashimiXWing,
+            Technique::NakedTriple,
+            Technique::Swordfish,
+            Technique::HiddenTriple,
+            Technique::FinnedSwordfish,
+            Technique::SashimiSwordfish,
+            Technique::EmptyRectangle,
+            Technique::SimpleColoring,
+            Technique::XYWing,
+            Technique::XYZWing,
+            Technique::WWing,
+            Technique::UniqueRectangleType1,
+            Technique::UniqueRectangleType2,
+            Technique::UniqueRectangleType3,
+            Technique::UniqueRectangleType4,
+            Technique::NakedQuad,
+            Technique::Jellyfish,
+            Technique::HiddenQuad,
+            Technique::BugPlusOne,
+            Technique::FinnedJellyfish,
+            Technique::SashimiJellyfish,
+            Technique::XChain,
+            Technique::XYChain,
+            Technique::Aic,
+            Technique::AlsXz,
+            Technique::AlsXyWing,
+            Technique::DeathBlossom,
+            Technique::CellForcingChain,
+            Technique::UnitForcingChain,
+        ]
+    }
+}
+
+impl Display for Technique {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Technique::HiddenSingle => write!(f, "Hidden Single"),
+            Technique::NakedSingle => write!(f, "Naked Single"),
+            Technique::Pointing => write!(f, "Pointing"),
+            Technique::Claiming => write!(f, "Claiming"),
+            Technique::NakedPair => write!(f, "Naked Pair"),
+            Technique::HiddenPair => write!(f, "Hidden Pair"),
+            Technique::NakedTriple => write!(f, "Naked Triple"),
+            Technique::HiddenTriple => write!(f, "Hidden Triple"),
+            Technique::NakedQuad => write!(f, "Naked Quad"),
+            Technique::HiddenQuad => write!(f, "Hidden Quad"),
+            Technique::XWing => write!(f, "X-Wing"),
+            Technique::FinnedXWing => write!(f, "Finned X-Wing"),
+            Technique::SashimiXWing => write!(f, "Sashimi X-Wing"),
+            Technique::Swordfish => write!(f, "Swordfish"),
+            Technique::FinnedSwordfish => write!(f, "Finned Swordfish"),
+            Technique::SashimiSwordfish => write!(f, "Sashimi Swordfish"),
+            Technique::Jellyfish => write!(f, "Jellyfish"),
+            Technique::FinnedJellyfish => write!(f, "Finned Jellyfish"),
+            Technique::SashimiJellyfish => write!(f, "Sashimi Jellyfish"),
+            Technique::XYWing => write!(f, "XY-Wing"),
+            Technique::XYZWing => write!(f, "XYZ-Wing"),
+            Technique::WWing => write!(f, "W-Wing"),
+            Technique::Skyscraper => write!(f, "Skyscraper"),
+            Technique::TwoStringKite => write!(f, "2-String Kite"),
+            Technique::EmptyRectangle => write!(f, "Empty Rectangle"),
+            Technique::SimpleColoring => write!(f, "Simple Coloring"),
+            Technique::UniqueRectangleType1 => write!(f, "Unique Rectangle Type 1"),
+            Technique::UniqueRectangleType2 => write!(f, "Unique Rectangle Type 2"),
+            Technique::UniqueRectangleType3 => write!(f, "Unique Rectangle Type 3"),
+            Technique::UniqueRectangleType4 => write!(f, "Unique Rectangle Type 4"),
+            Technique::BugPlusOne => write!(f, "BUG+1"),
+            Technique::XChain => write!(f, "X-Chain"),
+            Technique::XYChain => write!(f, "XY-Chain"),
+            Technique::Aic => write!(f, "Alternating Inference Chain"),
+            Technique::AlsXz => write!(f, "ALS-XZ"),
+            Technique::AlsXyWing => write!(f, "ALS-XY-Wing"),
+            Technique::DeathBlossom => write!(f, "Death Blossom"),
+            Technique::CellForcingChain => write!(f, "Cell Forcing Chain"),
+            Technique::UnitForcingChain => write!(f, "Unit Forcing Chain"),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! Techniques assuming the board has a unique solution, they must only be used once that is
+//! established.
+
+use crate::{
+    game::{CandidateSet, Value},
+    units::{box_of, sees, HOUSES},
+};
+
+use super::{
+    grid::{combinations, house, pos, Grid},
+    Step, Technique,
+};
+
+/// Four empty cells in two rows, two columns and two boxes sharing two possible values.
+struct Rectangle {
+    cells: [usize; 4],
+    pair: CandidateSet,
+}
+
+impl Rectangle {
+    /// Returns the cells with possible values besides the pair.
+    fn roof(&self, grid: &Grid) -> Vec<usize> {
+        self.cells
+            .iter()
+            .copied()
+            .filter(|&cell| grid.candidates[cell] != self.pair)
+            .collect()
+    }
+
+    /// Returns the houses of the rectangle, rows first.
+    fn houses(&self) -> Vec<usize> {
+        let [top_left, _, _, bottom_right] = self.cells;
+        vec![
+            top_left / 9,
+            bottom_right / 9,
+            9 + top_left % 9,
+            9 + bottom_right % 9,
+        ]
+    }
+
+    fn step(&self, technique: Technique) -> Step {
+        let mut step = Step::new(technique, self.pair);
+        step.cells = self.cells.iter().map(|&cell| pos(cell)).collect();
+        step.houses = self.houses().into_iter().map(house).collect();
+        step
+    }
+}
+
+/// Returns all rectangles, once for each pair of possible values they share.
+fn rectangles(grid: &Grid) -> Vec<Rectangle> {
+    let mut rectangles = Vec::new();
+    for (r1, r2) in (0..9).flat_map(|r1| (r1 + 1..9).map(move |r2| (r1, r2))) {
+        for (c1, c2) in (0..9).flat_map(|c1| (c1 + 1..9).map(move |c2| (c1, c2))) {
+            // exactly one of the rows or columns share a band or stack, giving two boxes
+            if (r1 / 3 == r2 / 3) == (c1 / 3 == c2 / 3) {
+                continue;
+            }
+
+            let cells = [r1 * 9 + c1, r1 * 9 + c2, r2 * 9 + c1, r2 * 9 + c2];
+            if cells.iter().any(|&cell| grid.values[cell] != Value::None) {
+                continue;
+            }
+            let shared = cells.iter().fold(CandidateSet::full(), |shared, &cell| {
+                shared & grid.candidates[cell]
+            });
+            let shared = shared.into_iter().collect::<Vec<_>>();
+            for pair in combinations(&shared, 2) {
+                rectangles.push(Rectangle {
+                    cells,
+                    pair: pair.into_iter().collect(),
+                });
+            }
+        }
+    }
+    rectangles
+}
+
+/// Finds a rectangle with only the pair possible in three cells, the pair is removed from the
+/// fourth.
+pub(crate) fn unique_rectangle_type_1(grid: &Grid) -> Option<Step> {
+    rectangles(grid).into_iter().find_map(|rectangle| {
+        let [roof] = rectangle.roof(grid)[..] else {
+            return None;
+        };
+
+        let (row, col) = pos(roof);
+        let mut step = rectangle.step(Technique::UniqueRectangleType1);
+        step.eliminations = rectangle
+            .pair
+            .into_iter()
+            .map(|value| (row, col, value))
+            .collect();
+        Some(step)
+    })
+}
+
+/// Finds a rectangle with two cells having the same single extra value, the extra value is
+/// removed from the cells seeing both.
+pub(crate) fn unique_rectangle_type_2(grid: &Grid) -> Option<Step> {
+    rectangles(grid).into_iter().find_map(|rectangle| {
+        let [first, second] = rectangle.roof(grid)[..] else {
+            return None;
+        };
+        if grid.candidates[first] != grid.candidates[second] {
+            return None;
+        }
+        let extra = (grid.candidates[first] - rectangle.pair).only()?;
+
+        let eliminations = (0..81)
+            .filter(|&cell| cell != first && cell != second)
+            .filter(|&cell| grid.candidates[cell].contains(extra))
+            .filter(|&cell| sees(cell, first) && sees(cell, second))
+            .map(|cell| {
+                let (row, col) = pos(cell);
+                (row, col, extra)
+            })
+            .collect::<Vec<_>>();
+        if eliminations.is_empty() {
+            return None;
+        }
+
+        let mut step = rectangle.step(Technique::UniqueRectangleType2);
+        step.eliminations = eliminations;
+        Some(step)
+    })
+}
+
+/// Finds a rectangle with two cells of a house having extra values, which form a naked subset
+/// with other cells of the house. The subset values are removed from the rest of the house.
+pub(crate) fn unique_rectangle_type_3(grid: &Grid) -> Option<Step> {
+    rectangles(grid).into_iter().find_map(|rectangle| {
+        let roof = rectangle.roof(grid);
+        let [first, second] = roof[..] else {
+            return None;
+        };
+        let extra = (grid.candidates[first] | grid.candidates[second]) - rectangle.pair;
+
+        shared_houses(first, second).into_iter().find_map(|h| {
+            let others = HOUSES[h]
+                .into_iter()
+                .filter(|&cell| grid.values[cell] == Value::None && !roof.contains(&cell))
+                .collect::<Vec<_>>();
+
+            // the two cells act as one cell with the extra values
+            (1..=3).find_map(|size| {
+                combinations(&others, size).into_iter().find_map(|cells| {
+                    let digits = cells
+                        .iter()
+                        .fold(extra, |digits, &cell| digits | grid.candidates[cell]);
+                    if digits.len() != size + 1 {
+                        return None;
+                    }
+
+                    let eliminations = others
+                        .iter()
+                        .filter(|cell| !cells.contains(cell))
+                        .flat_map(|&cell| {
+                            (grid.candidates[cell] & digits)
+                                .into_iter()
+                                .map(move |value| {
+                                    let (row, col) = pos(cell);
+                                    (row, col, value)
+                                })
+                        })
+                        .collect::<Vec<_>>();
+                    if eliminations.is_empty() {
+                        return None;
+                    }
+
+                    let mut step = rectangle.step(Technique::UniqueRectangleType3);
+                    step.digits = rectangle.pair | digits;
+                    step.cells.extend(cells.iter().map(|&cell| pos(cell)));
+                    if !step.houses.contains(&house(h)) {
+                        step.houses.push(house(h));
+                    }
+                    step.eliminations = eliminations;
+                    Some(step)
+                })
+            })
+        })
+    })
+}
+
+/// Finds a rectangle with two cells of a house being the only possible cells of one of the
+/// pair in that house, the other value of the pair is removed from both.
+pub(crate) fn unique_rectangle_type_4(grid: &Grid) -> Option<Step> {
+    rectangles(grid).into_iter().find_map(|rectangle| {
+        let [first, second] = rectangle.roof(grid)[..] else {
+            return None;
+        };
+
+        shared_houses(first, second).into_iter().find_map(|h| {
+            rectangle.pair.into_iter().find_map(|value| {
+                if grid.cells_with(h, value) != [first, second] {
+                    return None;
+                }
+                let other = (rectangle.pair - CandidateSet::single(value)).only()?;
+
+                let mut step = rectangle.step(Technique::UniqueRectangleType4);
+                if !step.houses.contains(&house(h)) {
+                    step.houses.push(house(h));
+                }
+                step.eliminations = [first, second]
+                    .into_iter()
+                    .map(|cell| {
+                        let (row, col) = pos(cell);
+                        (row, col, other)
+                    })
+                    .collect();
+                Some(step)
+            })
+        })
+    })
+}
+
+/// Finds a board where all empty cells have 2 possible values except one with 3, and each
+/// possible value is in 2 cells of each house (bivalue universal grave). <br>
+/// The value of the cell with 3 possible values that is in 3 cells of its houses is placed.
+pub(crate) fn bug_plus_one(grid: &Grid) -> Option<Step> {
+    let empty = (0..81)
+        .filter(|&cell| grid.values[cell] == Value::None)
+        .collect::<Vec<_>>();
+    let [cell] = empty
+        .iter()
+        .copied()
+        .filter(|&cell| grid.candidates[cell].len() != 2)
+        .collect::<Vec<_>>()[..]
+    else {
+        return None;
+    };
+    if grid.candidates[cell].len() != 3 {
+        return None;
+    }
+
+    let (row, col) = pos(cell);
+    let houses = [row - 1, 9 + col - 1, 18 + box_of(cell)];
+    let value = grid.candidates[cell]
+        .into_iter()
+        .find(|&value| houses.iter().all(|&h| grid.cells_with(h, value).len() == 3))?;
+
+    // without the value in the cell, every house must have each possible value twice
+    let count = |h: usize, v: Value| {
+        let count = grid.cells_with(h, v).len();
+        if v == value && houses.contains(&h) {
+            count - 1
+        } else {
+            count
+        }
+    };
+    if (0..27).any(|h| {
+        (1..=9)
+            .map(Value::from_usize)
+            .any(|v| ![0, 2].contains(&count(h, v)))
+    }) {
+        return None;
+    }
+
+    let mut step = Step::new(Technique::BugPlusOne, CandidateSet::single(value));
+    step.cells.push((row, col));
+    step.houses = houses.into_iter().map(house).collect();
+    step.placement = Some((row, col, value));
+    Some(step)
+}
+
+/// Returns the indices of the houses containing both cells.
+fn shared_houses(a: usize, b: usize) -> Vec<usize> {
+    (0..27)
+        .filter(|&h| HOUSES[h].contains(&a) && HOUSES[h].contains(&b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::SolutionCount,
+        logic::LogicalSolver,
+        testing::{board, check_steps, TWO_SOLUTIONS},
+    };
+
+    #[test]
+    fn not_used_on_multiple_solutions() {
+        // a unique rectangle is left open, uniqueness techniques would otherwise apply on the way
+        let mut board = board(TWO_SOLUTIONS);
+        assert_eq!(board.count_solutions(3), SolutionCount::Exactly(2));
+
+        let solver = LogicalSolver::new();
+        while let Some(step) = solver.next_step(&board) {
+            assert!(
+                !step.technique.requires_uniqueness(),
+                "{step} used on a board with 2 solutions"
+            );
+            assert!(LogicalSolver::apply(&mut board, &step));
+        }
+        assert!(!board.is_solved());
+    }
+
+    #[test]
+    fn agree_with_the_solution() {
+        let puzzles = [
+            ".245..........3.9413..7....4..1..5.3..8.3.........5.8...2.1..3....8....5.1....2.6",
+            "...1...35653..2........6....359..4....7......1..2....8.9.5.....2....7..1......3..",
+            ".............291.7..58...2621......9.7.1........54.8....1..4............962..5.31",
+            "...61....9..8..2..6..3.2.9.7..19.36.4.8....7.......5.8..7......5.3..48.....9.3...",
+        ];
+
+        let solver = LogicalSolver::new();
+        let mut used = puzzles
+            .iter()
+            .flat_map(|puzzle| check_steps(&solver, puzzle))
+            .filter(|technique| technique.requires_uniqueness())
+            .collect::<Vec<_>>();
+        used.sort();
+        used.dedup();
+        assert_eq!(used.len(), 5, "only {used:?} were used");
+    }
+}
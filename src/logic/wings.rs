@@ -0,0 +1,176 @@
+use crate::{
+    game::{CandidateSet, Value},
+    units::{sees, PEERS},
+};
+
+use super::{
+    grid::{house, pos, Grid},
+    Step, Technique,
+};
+
+/// Finds a pivot with 2 possible values xy seeing pincers with xz and yz, z is removed from
+/// the cells seeing both pincers.
+pub(crate) fn xy_wing(grid: &Grid) -> Option<Step> {
+    (0..81)
+        .filter(|&pivot| grid.candidates[pivot].len() == 2)
+        .find_map(|pivot| wing(grid, pivot, Technique::XYWing))
+}
+
+/// Finds a pivot with 3 possible values xyz seeing pincers with xz and yz, z is removed from
+/// the cells seeing the pivot and both pincers.
+pub(crate) fn xyz_wing(grid: &Grid) -> Option<Step> {
+    (0..81)
+        .filter(|&pivot| grid.candidates[pivot].len() == 3)
+        .find_map(|pivot| wing(grid, pivot, Technique::XYZWing))
+}
+
+/// Searches pincers of a pivot, the pincers share one value z with each other and the
+/// other with the pivot.
+fn wing(grid: &Grid, pivot: usize, technique: Technique) -> Option<Step> {
+    let xyz = technique == Technique::XYZWing;
+    let pivot_values = grid.candidates[pivot];
+    let pincers = PEERS[pivot]
+        .iter()
+        .copied()
+        .filter(|&cell| {
+            let values = grid.candidates[cell];
+            values.len() == 2
+                && if xyz {
+                    values.is_subset(pivot_values)
+                } else {
+                    (values & pivot_values).len() == 1
+                }
+        })
+        .collect::<Vec<_>>();
+
+    pincers.iter().enumerate().find_map(|(i, &first)| {
+        pincers[i + 1..].iter().find_map(|&second| {
+            let values = grid.candidates[first] | grid.candidates[second];
+            let z = (grid.candidates[first] & grid.candidates[second]).only()?;
+            let covered = if xyz {
+                values == pivot_values
+            } else {
+                !pivot_values.contains(z) && values - CandidateSet::single(z) == pivot_values
+            };
+            if !covered {
+                return None;
+            }
+
+            // with a XYZ-Wing the pivot may be z as well
+            let pattern = if xyz {
+                vec![pivot, first, second]
+            } else {
+                vec![first, second]
+            };
+            let eliminations = eliminations_seen_by(grid, &pattern, z);
+            if eliminations.is_empty() {
+                return None;
+            }
+
+            let mut step = Step::new(technique, values);
+            step.cells = [pivot, first, second].into_iter().map(pos).collect();
+            step.pivot = Some(pos(pivot));
+            step.pincers = vec![pos(first), pos(second)];
+            step.eliminations = eliminations;
+            Some(step)
+        })
+    })
+}
+
+/// Finds two cells with the same 2 possible values xy not seeing each other, connected by
+/// a house where x is only possible in cells seeing either of them. <br>
+/// One of them is y, thus y is removed from the cells seeing both.
+pub(crate) fn w_wing(grid: &Grid) -> Option<Step> {
+    let pairs = (0..81)
+        .filter(|&cell| grid.candidates[cell].len() == 2)
+        .collect::<Vec<_>>();
+
+    pairs.iter().enumerate().find_map(|(i, &first)| {
+        let values = grid.candidates[first];
+        pairs[i + 1..]
+            .iter()
+            .filter(|&&second| grid.candidates[second] == values && !sees(first, second))
+            .find_map(|&second| {
+                values.into_iter().find_map(|x| {
+                    let y = (values - CandidateSet::single(x)).only()?;
+                    (0..27).find_map(|h| {
+                        let link = grid.cells_with(h, x);
+                        let [a, b] = link[..] else {
+                            return None;
+                        };
+                        let (a, b) = if sees(a, first) && sees(b, second) {
+                            (a, b)
+                        } else if sees(b, first) && sees(a, second) {
+                            (b, a)
+                        } else {
+                            return None;
+                        };
+                        if [a, b].iter().any(|cell| [first, second].contains(cell)) {
+                            return None;
+                        }
+
+                        let eliminations = eliminations_seen_by(grid, &[first, second], y);
+                        if eliminations.is_empty() {
+                            return None;
+                        }
+
+                        let mut step = Step::new(Technique::WWing, values);
+                        step.cells = [first, second, a, b].into_iter().map(pos).collect();
+                        step.houses = vec![house(h)];
+                        step.pincers = vec![pos(first), pos(second)];
+                        step.eliminations = eliminations;
+                        Some(step)
+                    })
+                })
+            })
+    })
+}
+
+/// Returns the removals of the value from the cells seeing all of the pattern cells.
+fn eliminations_seen_by(
+    grid: &Grid,
+    pattern: &[usize],
+    value: Value,
+) -> Vec<(usize, usize, Value)> {
+    (0..81)
+        .filter(|cell| !pattern.contains(cell))
+        .filter(|&cell| grid.candidates[cell].contains(value))
+        .filter(|&cell| pattern.iter().all(|&other| sees(cell, other)))
+        .map(|cell| {
+            let (row, col) = pos(cell);
+            (row, col, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logic::{LogicalSolver, Technique},
+        testing::check_steps,
+    };
+
+    #[test]
+    fn wings_agree_with_the_solution() {
+        let solver = LogicalSolver::new();
+        for (puzzle, technique) in [
+            (
+                "..3.......6...9..49..7.8.2..9.1.....2....3651....2.9.....4...8.34.8.72......12.4.",
+                Technique::XYWing,
+            ),
+            (
+                "1.9..........4..6..4...91.3.6....218.....8...91.........6.579..5...91..4......8.6",
+                Technique::XYZWing,
+            ),
+            (
+                "....54.97..461.....8.3...6.9...3..42..3......4......893.1..65..746.............7.",
+                Technique::WWing,
+            ),
+        ] {
+            assert!(
+                check_steps(&solver, puzzle).contains(&technique),
+                "no {technique} in {puzzle}"
+            );
+        }
+    }
+}
@@ -1,23 +1,50 @@
-#![feature(binary_heap_retain)]
-
 use std::io::Write;
 
-use crate::{
-    commands::{
-        AddCommand, ChangeCommand, Command, CommandResult, IndicateCommand, RemoveCommand,
-        SolveCommand,
-    },
-    game::SudokuBoard,
+use sudoku_solver::{
+    game::{CheckResult, SolutionCount},
+    generator::MAX_ATTEMPTS,
+    logic::Playback,
+    SudokuBoard,
+};
+
+use crate::commands::{
+    AddCommand, ChangeCommand, CheckCommand, Command, CommandResult, GenCommand, HintCommand,
+    IndicateCommand, RateCommand, RemoveCommand, SolveCommand, UniqueCommand,
 };
 
 mod commands;
-mod game;
 
 fn main() -> std::io::Result<()> {
-    let args = std::env::args().collect::<Vec<_>>();
-    let mut board = if args.len() == 2 {
-        let input = std::fs::read_to_string(&args[1]).unwrap();
-        SudokuBoard::new(input.lines().collect())
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    // `--strict` refuses to load setup files with contradictory givens
+    let strict = args.iter().any(|arg| arg == "--strict");
+    let path = args.iter().find(|arg| *arg != "--strict");
+
+    let mut board = if let Some(path) = path {
+        let input = match std::fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Can't read `{path}`: {e}");
+                std::process::exit(1);
+            }
+        };
+        match SudokuBoard::parse_lenient(&input) {
+            Ok((board, conflicts)) => {
+                for conflict in &conflicts {
+                    eprintln!("Contradictory givens: {conflict}");
+                }
+                if strict && !conflicts.is_empty() {
+                    std::process::exit(1);
+                } else if !conflicts.is_empty() {
+                    eprintln!("The latter of each contradictory given was dropped");
+                }
+                board
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
     } else {
         SudokuBoard::new(Vec::<String>::new())
     };
@@ -28,6 +55,11 @@ fn main() -> std::io::Result<()> {
         Box::new(RemoveCommand {}),
         Box::new(IndicateCommand {}),
         Box::new(SolveCommand {}),
+        Box::new(UniqueCommand {}),
+        Box::new(CheckCommand {}),
+        Box::new(RateCommand {}),
+        Box::new(HintCommand {}),
+        Box::new(GenCommand {}),
     ];
 
     println!("{board}");
@@ -39,7 +71,10 @@ fn main() -> std::io::Result<()> {
         print!(">");
         stdout.flush()?;
         let mut input = String::new();
-        stdin.read_line(&mut input).unwrap();
+        if stdin.read_line(&mut input)? == 0 {
+            // stdin was closed
+            break;
+        }
         let input = input.trim();
 
         if input == "quit" {
@@ -47,10 +82,21 @@ fn main() -> std::io::Result<()> {
         } else if input == "reset" {
             board = SudokuBoard::new(Vec::<String>::new());
             println!("{board}");
+            continue;
         }
 
-        let input_split = input.split("").collect::<Vec<&str>>();
-        let input_split = input_split[1..input_split.len() - 1].to_vec();
+        // a command is either a name followed by whitespace separated arguments, e.g. `s dlx`,
+        // or a single character followed by single character arguments, e.g. `a123`
+        let words = input.split_whitespace().collect::<Vec<&str>>();
+        let input_split = if words
+            .first()
+            .is_some_and(|&word| commands.iter().any(|command| command.name() == word))
+        {
+            words
+        } else {
+            let input_split = input.split("").collect::<Vec<&str>>();
+            input_split[1..input_split.len() - 1].to_vec()
+        };
         if input_split.is_empty() {
             continue;
         }
@@ -71,10 +117,8 @@ fn main() -> std::io::Result<()> {
 
         for command in &commands {
             if command.name() == input_split[0] {
-                if command.num_args() <= input_split.len() - 1 {
-                    match command
-                        .execute(&mut board, input_split[1..].to_vec())
-                    {
+                if command.num_args() < input_split.len() {
+                    match command.execute(&mut board, input_split[1..].to_vec()) {
                         CommandResult::ParseError => println!("{}", command.usage()),
 
                         CommandResult::AddCommandSuccess(v, row, col) => {
@@ -83,6 +127,9 @@ fn main() -> std::io::Result<()> {
                         }
                         CommandResult::AddCommandNoneValue => println!("Can't add a 0"),
                         CommandResult::AddCommandNotPossible => println!("Illegal move"),
+                        CommandResult::AddCommandIncorrect => {
+                            println!("Guard mode: the value disagrees with the solution")
+                        }
                         CommandResult::AddCommandAlreadySet => {
                             println!("To change a value, use the change command")
                         }
@@ -113,6 +160,9 @@ fn main() -> std::io::Result<()> {
                         CommandResult::ChangeCommandNotPossible => {
                             println!("Illegal move")
                         }
+                        CommandResult::ChangeCommandIncorrect => {
+                            println!("Guard mode: the value disagrees with the solution")
+                        }
                         CommandResult::ChangeCommandImmutable => {
                             println!("Can't change an immutable cell")
                         }
@@ -122,16 +172,100 @@ fn main() -> std::io::Result<()> {
                             break;
                         }
 
-                        CommandResult::SolveCommandSuccess => {
+                        CommandResult::SolveCommandSuccess(stats) => {
                             println!("{board}");
+                            if let Some(stats) = stats {
+                                println!("{stats}");
+                            }
                             break;
                         }
-                        CommandResult::SolveCommandFailure => {
-                            println!("The board that was supplied via a text file was in an invalid state and is not solvable!");
+                        CommandResult::SolveCommandOverlay(solution, stats) => {
+                            println!("{}", board.overlay(&solution));
+                            if let Some(stats) = stats {
+                                println!("{stats}");
+                            }
+                        }
+                        CommandResult::SolveCommandStuck(stats) => {
+                            println!("The solver got stuck, try another solver");
+                            if let Some(stats) = stats {
+                                println!("{stats}");
+                            }
+                        }
+                        CommandResult::SolveCommandPlayback(playback) => {
+                            // play shows the board where the playback ended
+                            board = play(*playback)?;
+                        }
+                        CommandResult::SolveCommandFailure(stats) => {
+                            println!("The givens of the board are not solvable!");
+                            if let Some(stats) = stats {
+                                println!("{stats}");
+                            }
                             break;
                         }
+                        CommandResult::SolveCommandMistakes(stats) => {
+                            println!("Your values make the board unsolvable, use `check` to find the mistakes");
+                            if let Some(stats) = stats {
+                                println!("{stats}");
+                            }
+                        }
+
+                        CommandResult::UniqueCommandSuccess(count) => match count {
+                            SolutionCount::Exactly(0) => println!("The board has no solution"),
+                            SolutionCount::Exactly(1) => {
+                                println!("The board has a unique solution")
+                            }
+                            _ => println!("The board has multiple solutions"),
+                        },
+
+                        CommandResult::CheckCommandSuccess(result) => {
+                            match result {
+                                CheckResult::Mistakes(mistakes) if mistakes.is_empty() => {
+                                    println!("All values are correct")
+                                }
+                                CheckResult::Mistakes(mistakes) => {
+                                    println!("{}", board.highlight(&mistakes));
+                                    println!("Incorrect values at {mistakes:?}");
+                                }
+                                CheckResult::NoSolution => println!("The givens have no solution"),
+                                CheckResult::MultipleSolutions => {
+                                    println!("The givens have multiple solutions, values can't be checked")
+                                }
+                            }
+                        }
+                        CommandResult::CheckCommandGuard(on) => {
+                            println!("Guard mode {}", if on { "on" } else { "off" })
+                        }
 
-                        CommandResult::IndicateCommandSuccess(_) => {
+                        CommandResult::RateCommandSuccess(rating) => println!("{rating}"),
+
+                        CommandResult::GenCommandSuccess(rating) => {
+                            println!("{board}");
+                            println!("{rating}");
+                        }
+                        CommandResult::GenCommandFailed(grade) => {
+                            println!(
+                                "No {grade} puzzle found in {MAX_ATTEMPTS} attempts, try again"
+                            )
+                        }
+
+                        CommandResult::HintCommandSuccess(step, detail) => {
+                            if detail >= 2 {
+                                println!("{}", board.highlight(&step.cells));
+                            }
+                            for line in HintCommand::lines(&step, detail) {
+                                println!("{line}");
+                            }
+                        }
+                        CommandResult::HintCommandNone(solved) => {
+                            if solved {
+                                println!("The board is solved")
+                            } else {
+                                println!("No logical step found, try the solver")
+                            }
+                        }
+
+                        CommandResult::IndicateCommandSuccess(on) => {
+                            println!("Indicators {}", if on { "on" } else { "off" });
                             println!("{board}");
                         }
                     }
@@ -144,3 +278,56 @@ fn main() -> std::io::Result<()> {
 
     Ok(())
 }
+
+/// Steps through the playback until the user quits, returning the board at that point.
+fn play(mut playback: Playback) -> std::io::Result<SudokuBoard> {
+    println!("`n` or enter steps forward, `b` steps back, `q` keeps the board at the current step");
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    loop {
+        match playback.step() {
+            Some(step) => {
+                println!("{}", playback.board().highlight(&step.affected_cells()));
+                println!(
+                    "Step {}/{}: {}",
+                    playback.position() + 1,
+                    playback.len(),
+                    step.technique
+                );
+                println!("{}", step.explanation());
+            }
+            None => {
+                // playback ran out of steps, show where it ended
+                println!("{}", playback.board());
+                if playback.is_solved() {
+                    println!("The board is solved");
+                } else {
+                    println!("The logical solver got stuck here, try another solver");
+                }
+            }
+        }
+
+        print!("play>");
+        stdout.flush()?;
+        let mut input = String::new();
+        if stdin.read_line(&mut input)? == 0 {
+            break;
+        }
+        match input.trim() {
+            "" | "n" => {
+                if !playback.forward() {
+                    break;
+                }
+            }
+            "b" => {
+                if !playback.back() {
+                    println!("This is the first step");
+                }
+            }
+            "q" => break,
+            _ => println!("Use `n`, `b` or `q`"),
+        }
+    }
+
+    Ok(playback.board().clone())
+}
@@ -0,0 +1,77 @@
+use crate::{
+    game::{SolveResult, SudokuBoard},
+    logic::LogicalSolver,
+};
+
+mod dfs;
+pub use dfs::DfsSolver;
+mod dlx;
+pub use dlx::{Dlx, DlxSolver};
+mod propagation;
+pub use propagation::PropagationSolver;
+mod solutions;
+pub use solutions::Solutions;
+
+/// Returns all available solvers, the default [`DfsSolver`] first.
+pub fn solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(DfsSolver),
+        Box::new(DlxSolver),
+        Box::new(PropagationSolver),
+        Box::new(LogicalSolver::new()),
+    ]
+}
+
+/// Returns the solver with the given short name.
+pub fn solver_by_name(name: &str) -> Option<Box<dyn Solver>> {
+    solvers().into_iter().find(|solver| solver.name() == name)
+}
+
+/// A strategy to solve a [`SudokuBoard`].
+pub trait Solver {
+    /// Returns the short name of the solver.
+    fn name(&self) -> &'static str;
+    /// Solves the board in place, on failure it is left in its original state. <br>
+    /// Both outcomes carry the statistics of the search.
+    fn solve(&self, board: &mut SudokuBoard) -> SolveResult;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{board, SEVENTEEN, UNIQUE};
+
+    #[test]
+    fn solvers_agree() {
+        for puzzle in [UNIQUE, SEVENTEEN] {
+            let mut expected = board(puzzle);
+            assert!(matches!(expected.solve(), SolveResult::Solved(_)));
+
+            for solver in solvers() {
+                let name = solver.name();
+                assert!(solver_by_name(name).is_some_and(|other| other.name() == name));
+                let mut board = board(puzzle);
+                assert!(
+                    matches!(board.solve_with(solver.as_ref()), SolveResult::Solved(_)),
+                    "{name} failed"
+                );
+                assert_eq!(board.to_string(), expected.to_string(), "{name} differs");
+            }
+        }
+        assert!(solver_by_name("bfs").is_none());
+    }
+
+    #[test]
+    fn search_solvers_count_their_work() {
+        for solver in solvers().iter().filter(|solver| solver.name() != "logic") {
+            let name = solver.name();
+            let SolveResult::Solved(stats) = board(SEVENTEEN).solve_with(solver.as_ref()) else {
+                panic!("{name} failed");
+            };
+            assert!(stats.nodes > 0, "{name}: {stats}");
+            assert!(stats.backtracks <= stats.nodes, "{name}: {stats}");
+            assert!(stats.max_depth <= stats.nodes, "{name}: {stats}");
+            assert!(stats.guesses + stats.propagations > 0, "{name}: {stats}");
+        }
+    }
+}
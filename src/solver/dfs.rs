@@ -0,0 +1,146 @@
+use std::time::Instant;
+
+use crate::{
+    game::{
+        AddResult, CandidateSet, PossibleCellValues, SolveResult, SolveStats, SudokuBoard, Value,
+    },
+    units::HOUSES,
+};
+
+use super::Solver;
+
+/// Depth first search solver, branching on the cell with the least possible values unless a
+/// value has a single possible cell in a house (hidden single).
+pub struct DfsSolver;
+
+impl DfsSolver {
+    /// DFS algorithm to solve the board, returns true if the board was solved.
+    fn __solve(&self, board: &mut SudokuBoard, depth: usize, stats: &mut SolveStats) -> bool {
+        stats.visit(depth);
+        if let Some(PossibleCellValues { row, col, values }) = Self::__branch(board) {
+            for value in values {
+                if values.len() > 1 {
+                    stats.guesses += 1;
+                } else {
+                    stats.propagations += 1;
+                }
+
+                match board.add(row, col, value) {
+                    AddResult::Solved => return true,
+                    AddResult::Added(_) => {
+                        if self.__solve(board, depth + 1, stats) {
+                            return true;
+                        }
+                        // value didn't lead to a solution, remove it and continue with the next value.
+                        board.remove(row, col);
+                        stats.backtracks += 1;
+                    }
+                    // value was not possible, continue with the next value.
+                    AddResult::NotPossible | AddResult::Incorrect => continue,
+                    _ => panic!("Impossible program state"),
+                };
+            }
+        }
+
+        board.is_solved()
+    }
+
+    /// Returns the cell to branch on, the most constrained cell or a hidden single if that cell has
+    /// several possible values. <br>
+    /// `None` is returned if there are no empty cells, a cell without possible values if a house
+    /// has no possible cell left for one of its missing values.
+    fn __branch(board: &SudokuBoard) -> Option<PossibleCellValues> {
+        let cell = board.most_constrained_cell()?;
+        if cell.values.len() <= 1 {
+            return Some(cell);
+        }
+
+        for house in &HOUSES {
+            let (mut placed, mut once, mut twice) = (CandidateSet::empty(), 0, 0);
+            for &index in house {
+                let (row, col) = (index / 9 + 1, index % 9 + 1);
+                match board.value(row, col) {
+                    Value::None => {
+                        let bits = board.candidates(row, col).bits();
+                        twice |= once & bits;
+                        once |= bits;
+                    }
+                    value => placed.insert(value),
+                }
+            }
+
+            let missing = !placed;
+            if !(missing - CandidateSet::from_bits(once)).is_empty() {
+                return Some(PossibleCellValues {
+                    values: CandidateSet::empty(),
+                    ..cell
+                });
+            }
+            let Some(value) = (missing - CandidateSet::from_bits(twice)).first() else {
+                continue;
+            };
+            let index = house.iter().copied().find(|&index| {
+                board
+                    .candidates(index / 9 + 1, index % 9 + 1)
+                    .contains(value)
+            })?;
+            return Some(PossibleCellValues {
+                row: index / 9 + 1,
+                col: index % 9 + 1,
+                values: CandidateSet::single(value),
+            });
+        }
+
+        Some(cell)
+    }
+
+    /// Counts the solutions of the board with DFS, stopping once `limit` solutions are found. <br>
+    /// The board is left in its original state.
+    pub fn count_solutions(&self, board: &mut SudokuBoard, limit: usize) -> usize {
+        let mut count = 0;
+        self.__count_solutions(board, limit, &mut count);
+        count
+    }
+
+    fn __count_solutions(&self, board: &mut SudokuBoard, limit: usize, count: &mut usize) {
+        let Some(PossibleCellValues { row, col, values }) = Self::__branch(board) else {
+            // no empty cells left
+            *count += 1;
+            return;
+        };
+
+        for value in values {
+            if *count >= limit {
+                return;
+            }
+
+            match board.add(row, col, value) {
+                AddResult::Solved => *count += 1,
+                AddResult::Added(_) => self.__count_solutions(board, limit, count),
+                // value was not possible, continue with the next value.
+                AddResult::NotPossible | AddResult::Incorrect => continue,
+                _ => panic!("Impossible program state"),
+            }
+            board.remove(row, col);
+        }
+    }
+}
+
+impl Solver for DfsSolver {
+    fn name(&self) -> &'static str {
+        "dfs"
+    }
+
+    fn solve(&self, board: &mut SudokuBoard) -> SolveResult {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let solved = self.__solve(board, 0, &mut stats);
+        stats.time = start.elapsed();
+
+        if solved {
+            SolveResult::Solved(stats)
+        } else {
+            SolveResult::Failed(stats)
+        }
+    }
+}
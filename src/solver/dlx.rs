@@ -0,0 +1,280 @@
+use std::time::Instant;
+
+use crate::{
+    game::{AddResult, SolveResult, SolveStats, SudokuBoard, Value},
+    units::box_of,
+};
+
+use super::Solver;
+
+/// Sparse exact cover matrix, linked as Knuth's dancing links. <br>
+/// Node 0 is the root, nodes 1..=columns are the column headers, the rest are the 1s of the matrix.
+pub struct Dlx {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column: Vec<usize>,
+    row: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl Dlx {
+    /// Creates a matrix with `columns` columns and no rows.
+    pub fn new(columns: usize) -> Self {
+        let mut dlx = Dlx {
+            left: Vec::new(),
+            right: Vec::new(),
+            up: Vec::new(),
+            down: Vec::new(),
+            column: Vec::new(),
+            row: Vec::new(),
+            size: vec![0; columns + 1],
+        };
+
+        for node in 0..=columns {
+            dlx.left.push(if node == 0 { columns } else { node - 1 });
+            dlx.right.push(if node == columns { 0 } else { node + 1 });
+            dlx.up.push(node);
+            dlx.down.push(node);
+            dlx.column.push(node);
+            dlx.row.push(usize::MAX);
+        }
+
+        dlx
+    }
+
+    /// Adds a row with 1s in the given 0 based columns, `id` is returned as part of solutions.
+    pub fn add_row(&mut self, id: usize, columns: &[usize]) {
+        let first = self.left.len();
+        for (i, &col) in columns.iter().enumerate() {
+            let header = col + 1;
+            let node = first + i;
+
+            // insert at the bottom of the column
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            let bottom = self.up[header];
+            self.down[bottom] = node;
+            self.up[header] = node;
+            self.size[header] += 1;
+
+            // link into the row
+            self.left.push(if i == 0 {
+                first + columns.len() - 1
+            } else {
+                node - 1
+            });
+            self.right.push(if i == columns.len() - 1 {
+                first
+            } else {
+                node + 1
+            });
+            self.column.push(header);
+            self.row.push(id);
+        }
+    }
+
+    /// Searches for an exact cover and returns the ids of its rows. <br>
+    /// The matrix is left partially covered and should not be searched again.
+    pub fn solve(&mut self, stats: &mut SolveStats) -> Option<Vec<usize>> {
+        let mut solution = Vec::new();
+        if self.__search(&mut solution, 0, stats) {
+            Some(solution)
+        } else {
+            None
+        }
+    }
+
+    fn __search(
+        &mut self,
+        solution: &mut Vec<usize>,
+        depth: usize,
+        stats: &mut SolveStats,
+    ) -> bool {
+        stats.visit(depth);
+        if self.right[0] == 0 {
+            return true;
+        }
+
+        // branch on the column with the least 1s
+        let mut header = self.right[0];
+        let mut col = header;
+        while col != 0 {
+            if self.size[col] < self.size[header] {
+                header = col;
+            }
+            col = self.right[col];
+        }
+        if self.size[header] == 0 {
+            return false;
+        }
+
+        self.__cover(header);
+        let mut node = self.down[header];
+        while node != header {
+            if self.size[header] > 1 {
+                stats.guesses += 1;
+            } else {
+                stats.propagations += 1;
+            }
+
+            solution.push(self.row[node]);
+            let mut j = self.right[node];
+            while j != node {
+                self.__cover(self.column[j]);
+                j = self.right[j];
+            }
+
+            if self.__search(solution, depth + 1, stats) {
+                return true;
+            }
+            stats.backtracks += 1;
+
+            let mut j = self.left[node];
+            while j != node {
+                self.__uncover(self.column[j]);
+                j = self.left[j];
+            }
+            solution.pop();
+            node = self.down[node];
+        }
+        self.__uncover(header);
+
+        false
+    }
+
+    /// Removes the column and all rows with a 1 in it.
+    fn __cover(&mut self, header: usize) {
+        self.right[self.left[header]] = self.right[header];
+        self.left[self.right[header]] = self.left[header];
+
+        let mut i = self.down[header];
+        while i != header {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    /// Restores a column removed by [`Dlx::__cover`].
+    fn __uncover(&mut self, header: usize) {
+        let mut i = self.up[header];
+        while i != header {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+
+        self.right[self.left[header]] = header;
+        self.left[self.right[header]] = header;
+    }
+}
+
+/// Exact cover solver using Algorithm X on dancing links. <br>
+/// Each cell, row, column and 3x3 square value is a column, each possible value of a cell a row.
+pub struct DlxSolver;
+
+impl DlxSolver {
+    /// Builds the exact cover matrix of the board, row ids are `(row * 9 + col) * 9 + value - 1`.
+    fn __matrix(board: &SudokuBoard) -> Dlx {
+        let mut dlx = Dlx::new(4 * 81);
+        for row in 0..9 {
+            for col in 0..9 {
+                let value = board.value(row + 1, col + 1);
+                let values = if value != Value::None {
+                    std::iter::once(value).collect()
+                } else {
+                    board.candidates(row + 1, col + 1)
+                };
+
+                for value in values {
+                    let v = value.to_usize() - 1;
+                    let square = box_of(row * 9 + col);
+                    dlx.add_row(
+                        (row * 9 + col) * 9 + v,
+                        &[
+                            row * 9 + col,
+                            81 + row * 9 + v,
+                            2 * 81 + col * 9 + v,
+                            3 * 81 + square * 9 + v,
+                        ],
+                    );
+                }
+            }
+        }
+        dlx
+    }
+}
+
+impl Solver for DlxSolver {
+    fn name(&self) -> &'static str {
+        "dlx"
+    }
+
+    fn solve(&self, board: &mut SudokuBoard) -> SolveResult {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let solution = Self::__matrix(board).solve(&mut stats);
+        stats.time = start.elapsed();
+
+        let Some(solution) = solution else {
+            return SolveResult::Failed(stats);
+        };
+
+        for id in solution {
+            let (row, col) = (id / 81 + 1, id / 9 % 9 + 1);
+            if board.value(row, col) == Value::None {
+                match board.add(row, col, Value::from_usize(id % 9 + 1)) {
+                    AddResult::Added(_) | AddResult::Solved => {}
+                    _ => panic!("Impossible program state"),
+                }
+            }
+        }
+
+        SolveResult::Solved(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::SolveResult,
+        solver::DfsSolver,
+        testing::{board, SEVENTEEN, UNIQUE},
+    };
+
+    #[test]
+    fn agrees_with_dfs() {
+        for puzzle in [UNIQUE, SEVENTEEN] {
+            let mut dfs = board(puzzle);
+            let mut dlx = board(puzzle);
+            assert!(matches!(dfs.solve_with(&DfsSolver), SolveResult::Solved(_)));
+            assert!(matches!(dlx.solve_with(&DlxSolver), SolveResult::Solved(_)));
+            assert!(dlx.is_solved());
+            assert_eq!(dlx.to_string(), dfs.to_string());
+        }
+    }
+
+    #[test]
+    fn fails_without_changing_the_board() {
+        let mut board = SudokuBoard::parse("1|2|3|4|5|6|7|8|\n||||||||9").unwrap();
+        let before = board.to_string();
+        assert!(matches!(
+            board.solve_with(&DlxSolver),
+            SolveResult::Failed(_)
+        ));
+        assert_eq!(board.to_string(), before);
+    }
+}
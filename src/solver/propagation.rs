@@ -0,0 +1,164 @@
+use std::time::Instant;
+
+use crate::{
+    game::{AddResult, CandidateSet, SolveResult, SolveStats, SudokuBoard, Value},
+    units::{HOUSES, PEERS},
+};
+
+use super::Solver;
+
+/// Candidates of all cells, placed cells have a single candidate.
+#[derive(Clone, Copy)]
+struct State {
+    candidates: [CandidateSet; 81],
+    placed: u128,
+}
+
+impl State {
+    fn new(board: &SudokuBoard) -> Self {
+        let mut state = State {
+            candidates: [CandidateSet::empty(); 81],
+            placed: 0,
+        };
+
+        for (i, candidates) in state.candidates.iter_mut().enumerate() {
+            let value = board.value(i / 9 + 1, i % 9 + 1);
+            if value != Value::None {
+                *candidates = CandidateSet::single(value);
+                state.placed |= 1 << i;
+            } else {
+                *candidates = board.candidates(i / 9 + 1, i % 9 + 1);
+            }
+        }
+
+        state
+    }
+
+    fn is_placed(&self, cell: usize) -> bool {
+        self.placed & (1 << cell) != 0
+    }
+
+    /// Places naked and hidden singles until none are left. <br>
+    /// Returns false if a cell or house runs out of candidates.
+    fn propagate(&mut self, stats: &mut SolveStats) -> bool {
+        loop {
+            let mut changed = false;
+
+            // naked singles
+            for (cell, peers) in PEERS.iter().enumerate() {
+                if self.is_placed(cell) {
+                    continue;
+                }
+
+                match self.candidates[cell].only() {
+                    Some(value) => {
+                        self.placed |= 1 << cell;
+                        for &peer in peers {
+                            self.candidates[peer].remove(value);
+                        }
+                        stats.propagations += 1;
+                        changed = true;
+                    }
+                    None if self.candidates[cell].is_empty() => return false,
+                    None => {}
+                }
+            }
+
+            // hidden singles
+            for house in HOUSES {
+                let mut seen_once = CandidateSet::empty();
+                let mut seen_twice = CandidateSet::empty();
+                for cell in house {
+                    seen_twice |= seen_once & self.candidates[cell];
+                    seen_once |= self.candidates[cell];
+                }
+                if seen_once != CandidateSet::full() {
+                    return false;
+                }
+
+                for cell in house {
+                    let singles = self.candidates[cell] & (seen_once - seen_twice);
+                    match singles.len() {
+                        0 => {}
+                        1 => {
+                            if !self.is_placed(cell) && self.candidates[cell].len() > 1 {
+                                self.candidates[cell] = singles;
+                                changed = true;
+                            }
+                        }
+                        // the cell is the only cell for several values
+                        _ => return false,
+                    }
+                }
+            }
+
+            if !changed {
+                return true;
+            }
+        }
+    }
+
+    /// Returns the unplaced cell with the least candidates.
+    fn most_constrained(&self) -> Option<usize> {
+        (0..81)
+            .filter(|&cell| !self.is_placed(cell))
+            .min_by_key(|&cell| self.candidates[cell].len())
+    }
+}
+
+/// Backtracking solver that propagates naked and hidden singles before every guess.
+pub struct PropagationSolver;
+
+impl PropagationSolver {
+    fn __search(&self, mut state: State, depth: usize, stats: &mut SolveStats) -> Option<State> {
+        stats.visit(depth);
+        if !state.propagate(stats) {
+            return None;
+        }
+
+        let Some(cell) = state.most_constrained() else {
+            return Some(state);
+        };
+
+        for value in state.candidates[cell] {
+            stats.guesses += 1;
+            let mut next = state;
+            next.candidates[cell] = CandidateSet::single(value);
+            if let Some(solution) = self.__search(next, depth + 1, stats) {
+                return Some(solution);
+            }
+            stats.backtracks += 1;
+        }
+
+        None
+    }
+}
+
+impl Solver for PropagationSolver {
+    fn name(&self) -> &'static str {
+        "prop"
+    }
+
+    fn solve(&self, board: &mut SudokuBoard) -> SolveResult {
+        let start = Instant::now();
+        let mut stats = SolveStats::default();
+        let solution = self.__search(State::new(board), 0, &mut stats);
+        stats.time = start.elapsed();
+
+        let Some(solution) = solution else {
+            return SolveResult::Failed(stats);
+        };
+
+        for (i, candidates) in solution.candidates.iter().enumerate() {
+            let (row, col) = (i / 9 + 1, i % 9 + 1);
+            if board.value(row, col) == Value::None {
+                match board.add(row, col, candidates.only().unwrap()) {
+                    AddResult::Added(_) | AddResult::Solved => {}
+                    _ => panic!("Impossible program state"),
+                }
+            }
+        }
+
+        SolveResult::Solved(stats)
+    }
+}
@@ -0,0 +1,163 @@
+use crate::game::{AddResult, CandidateSet, PossibleCellValues, SudokuBoard};
+
+/// A cell the search branched on and the values not tried yet.
+#[derive(Clone)]
+struct Branch {
+    row: usize,
+    col: usize,
+    remaining: CandidateSet,
+    placed: bool,
+}
+
+/// Lazy iterator over all solutions of a board, created by [`SudokuBoard::solutions`]. <br>
+/// The search runs on its own copy of the board, each solution is yielded as a solved board.
+/// Cloning the iterator snapshots the search, so both copies resume from the same solution.
+#[derive(Clone)]
+pub struct Solutions {
+    board: SudokuBoard,
+    stack: Vec<Branch>,
+    started: bool,
+    limit: Option<usize>,
+    found: usize,
+}
+
+impl Solutions {
+    /// Creates an iterator over the solutions of a copy of the board.
+    pub fn new(board: &SudokuBoard) -> Self {
+        Solutions {
+            board: board.__clone_without_guard(),
+            stack: Vec::new(),
+            started: false,
+            limit: None,
+            found: 0,
+        }
+    }
+
+    /// Stops the iterator after `limit` solutions.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the number of solutions yielded so far.
+    pub fn found(&self) -> usize {
+        self.found
+    }
+
+    /// Pushes the most constrained cell onto the stack, returns false if there are no empty cells.
+    fn __branch(&mut self) -> bool {
+        match self.board.most_constrained_cell() {
+            Some(PossibleCellValues { row, col, values }) => {
+                self.stack.push(Branch {
+                    row,
+                    col,
+                    remaining: values,
+                    placed: false,
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn __found(&mut self) -> Option<SudokuBoard> {
+        self.found += 1;
+        Some(self.board.clone())
+    }
+}
+
+impl Iterator for Solutions {
+    type Item = SudokuBoard;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.limit.is_some_and(|limit| self.found >= limit) {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            if !self.__branch() {
+                // the board has no empty cells and thus is its only solution
+                return self.__found();
+            }
+        }
+
+        while let Some(branch) = self.stack.last_mut() {
+            let (row, col) = (branch.row, branch.col);
+            if branch.placed {
+                // backtrack the value tried last
+                branch.placed = false;
+                self.board.remove(row, col);
+            }
+
+            let Some(value) = branch.remaining.first() else {
+                self.stack.pop();
+                continue;
+            };
+            branch.remaining.remove(value);
+
+            match self.board.add(row, col, value) {
+                AddResult::Solved => {
+                    branch.placed = true;
+                    return self.__found();
+                }
+                AddResult::Added(_) => {
+                    branch.placed = true;
+                    self.__branch();
+                }
+                // value was not possible, continue with the next value.
+                AddResult::NotPossible | AddResult::Incorrect => continue,
+                _ => panic!("Impossible program state"),
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::Value,
+        testing::{board, TWO_SOLUTIONS, UNIQUE},
+    };
+
+    #[test]
+    fn yields_every_solution_once() {
+        let board = board(TWO_SOLUTIONS);
+        let solutions = board.solutions().collect::<Vec<_>>();
+        assert_eq!(solutions.len(), 2);
+        assert!(solutions.iter().all(|solution| solution.is_solved()));
+        assert!((1..=9)
+            .flat_map(|row| (1..=9).map(move |col| (row, col)))
+            .any(|(row, col)| solutions[0].value(row, col) != solutions[1].value(row, col)));
+
+        // the board itself is not modified
+        assert_eq!(board.value(1, 1), Value::None);
+        assert!(!board.is_solved());
+    }
+
+    #[test]
+    fn stops_at_the_limit() {
+        let mut solutions = board(TWO_SOLUTIONS).solutions().limit(1);
+        assert!(solutions.next().is_some());
+        assert!(solutions.next().is_none());
+        assert_eq!(solutions.found(), 1);
+
+        assert_eq!(board(UNIQUE).solutions().limit(5).count(), 1);
+    }
+
+    #[test]
+    fn resumes_from_a_clone() {
+        let mut solutions = board(TWO_SOLUTIONS).solutions();
+        let first = solutions.next().unwrap();
+        let mut resumed = solutions.clone();
+
+        let second = solutions.next().unwrap();
+        let resumed_second = resumed.next().unwrap();
+        assert_eq!(second.to_string(), resumed_second.to_string());
+        assert_ne!(first.to_string(), second.to_string());
+        assert!(solutions.next().is_none() && resumed.next().is_none());
+        assert_eq!(resumed.found(), 2);
+    }
+}
@@ -0,0 +1,87 @@
+//! Helpers shared by the unit tests.
+
+use crate::{
+    logic::{LogicalSolver, Step, Technique},
+    SudokuBoard,
+};
+
+/// Creates a board from 81 digits in reading order, `.` for empty cells.
+pub(crate) fn board(puzzle: &str) -> SudokuBoard {
+    let input = puzzle
+        .as_bytes()
+        .chunks(9)
+        .map(|row| {
+            row.iter()
+                .map(|&c| match c {
+                    b'.' => String::new(),
+                    c => (c as char).to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("|")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    SudokuBoard::parse(&input).unwrap()
+}
+
+/// Solves the puzzle step by step, checking each placement and elimination against the solution.
+/// <br> Returns the techniques used, each once in order of first use.
+pub(crate) fn check_steps(solver: &LogicalSolver, puzzle: &str) -> Vec<Technique> {
+    let mut board = board(puzzle);
+    let solution = board.solution().unwrap();
+    let mut used = Vec::new();
+    while let Some(step) = solver.next_step(&board) {
+        if let Some((row, col, value)) = step.placement {
+            assert_eq!(solution.value(row, col), value, "{puzzle}: {step}");
+        }
+        for &(row, col, value) in &step.eliminations {
+            assert_ne!(solution.value(row, col), value, "{puzzle}: {step}");
+        }
+        if !used.contains(&step.technique) {
+            used.push(step.technique);
+        }
+        assert!(LogicalSolver::apply(&mut board, &step));
+    }
+    assert!(board.is_solved(), "{puzzle} is not solved");
+    used
+}
+
+/// Solves the puzzle step by step until a step matches. <br>
+/// Returns the board the step was found on and the step.
+pub(crate) fn find_step(
+    solver: &LogicalSolver,
+    puzzle: &str,
+    matches: impl Fn(&Step) -> bool,
+) -> (SudokuBoard, Step) {
+    let mut board = board(puzzle);
+    while let Some(step) = solver.next_step(&board) {
+        if matches(&step) {
+            return (board, step);
+        }
+        assert!(LogicalSolver::apply(&mut board, &step));
+    }
+    panic!("no matching step in {puzzle}");
+}
+
+/// Creates a solver using all techniques but the given ones, e.g. to leave a pattern to a
+/// harder technique that is otherwise preceded by an easier equivalent one.
+pub(crate) fn solver_without(techniques: &[Technique]) -> LogicalSolver {
+    let techniques = Technique::all()
+        .iter()
+        .copied()
+        .filter(|technique| !techniques.contains(technique))
+        .collect::<Vec<_>>();
+    LogicalSolver::with_techniques(&techniques)
+}
+
+/// A puzzle with a unique solution.
+pub(crate) const UNIQUE: &str =
+    "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+
+/// A puzzle with 2 solutions, differing in an open unique rectangle.
+pub(crate) const TWO_SOLUTIONS: &str =
+    "..4.....2.7.1.5.4....3..5....9..14....6....9.7..92.........7.........6.5.4..8.1..";
+
+/// A puzzle with 17 givens, the fewest possible for a unique solution.
+pub(crate) const SEVENTEEN: &str =
+    "...8.1..........435............7.8........1...2..3....6......75..34........2..6..";
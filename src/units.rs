@@ -0,0 +1,52 @@
+//! Precomputed cell index tables, cells are indexed `row * 9 + col` with `row` and `col` bounds 0..9.
+
+/// The cells of the 27 houses: rows 0..9, columns 9..18 and boxes 18..27.
+pub(crate) const HOUSES: [[usize; 9]; 27] = houses();
+
+/// The 20 cells sharing a house with each cell.
+pub(crate) const PEERS: [[usize; 20]; 81] = peers();
+
+const fn houses() -> [[usize; 9]; 27] {
+    let mut houses = [[0; 9]; 27];
+    let mut i = 0;
+    while i < 9 {
+        let mut j = 0;
+        while j < 9 {
+            houses[i][j] = i * 9 + j;
+            houses[9 + i][j] = j * 9 + i;
+            houses[18 + i][j] = (i / 3 * 3 + j / 3) * 9 + i % 3 * 3 + j % 3;
+            j += 1;
+        }
+        i += 1;
+    }
+    houses
+}
+
+const fn peers() -> [[usize; 20]; 81] {
+    let mut peers = [[0; 20]; 81];
+    let mut cell = 0;
+    while cell < 81 {
+        let mut count = 0;
+        let mut other = 0;
+        while other < 81 {
+            if other != cell && sees(cell, other) {
+                peers[cell][count] = other;
+                count += 1;
+            }
+            other += 1;
+        }
+        cell += 1;
+    }
+    peers
+}
+
+/// Returns the index of the box containing the cell, boxes are numbered left to right, top to
+/// bottom with bounds 0..9.
+pub(crate) const fn box_of(cell: usize) -> usize {
+    cell / 27 * 3 + cell % 9 / 3
+}
+
+/// Checks if two cells share a row, column or box.
+pub(crate) const fn sees(a: usize, b: usize) -> bool {
+    a / 9 == b / 9 || a % 9 == b % 9 || box_of(a) == box_of(b)
+}